@@ -35,6 +35,42 @@ namespace ScoogiBattle
 
             public ulong BattlePrice { get; set; }
 
+            public PublicKey ResultAuthority { get; set; }
+
+            public long DisputeWindowSeconds { get; set; }
+
+            public long BattleTimeoutSeconds { get; set; }
+
+            public ulong TreasuryFeeBps { get; set; }
+
+            public ulong ReferrerFeeBps { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public ulong MinStake { get; set; }
+
+            public ulong MaxStake { get; set; }
+
+            public PublicKey PendingAdmin { get; set; }
+
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey TreasuryAuthority { get; set; }
+
+            public PublicKey Pauser { get; set; }
+
+            public byte Paused { get; set; }
+
+            public long ConfigDelaySeconds { get; set; }
+
+            public PendingConfig PendingConfig { get; set; }
+
+            public byte Version { get; set; }
+
+            public long? PendingConfigDelaySeconds { get; set; }
+
+            public byte[] Reserved { get; set; }
+
             public static Admin Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -54,6 +90,46 @@ namespace ScoogiBattle
                 offset += 8;
                 result.BattlePrice = _data.GetU64(offset);
                 offset += 8;
+                result.ResultAuthority = _data.GetPubKey(offset);
+                offset += 32;
+                result.DisputeWindowSeconds = _data.GetS64(offset);
+                offset += 8;
+                result.BattleTimeoutSeconds = _data.GetS64(offset);
+                offset += 8;
+                result.TreasuryFeeBps = _data.GetU64(offset);
+                offset += 8;
+                result.ReferrerFeeBps = _data.GetU64(offset);
+                offset += 8;
+                result.TreasuryTokenAccount = _data.GetPubKey(offset);
+                offset += 32;
+                result.MinStake = _data.GetU64(offset);
+                offset += 8;
+                result.MaxStake = _data.GetU64(offset);
+                offset += 8;
+                result.PendingAdmin = _data.GetPubKey(offset);
+                offset += 32;
+                result.ConfigManager = _data.GetPubKey(offset);
+                offset += 32;
+                result.TreasuryAuthority = _data.GetPubKey(offset);
+                offset += 32;
+                result.Pauser = _data.GetPubKey(offset);
+                offset += 32;
+                result.Paused = _data.GetU8(offset);
+                offset += 1;
+                result.ConfigDelaySeconds = _data.GetS64(offset);
+                offset += 8;
+                offset += PendingConfig.Deserialize(_data, offset, out var resultPendingConfig);
+                result.PendingConfig = resultPendingConfig;
+                result.Version = _data.GetU8(offset);
+                offset += 1;
+                if (_data.GetBool(offset++))
+                {
+                    result.PendingConfigDelaySeconds = _data.GetS64(offset);
+                    offset += 8;
+                }
+
+                result.Reserved = _data.GetBytes(offset, 55);
+                offset += 55;
                 return result;
             }
         }
@@ -73,6 +149,42 @@ namespace ScoogiBattle
 
             public BattleStatus BattleStatus { get; set; }
 
+            public SettlementMode SettlementMode { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public ulong StakeAmount { get; set; }
+
+            public FeeSchedule FeeSchedule { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public byte? PlayerOneAttestation { get; set; }
+
+            public byte? PlayerTwoAttestation { get; set; }
+
+            public long DisputeDeadline { get; set; }
+
+            public long CreatedAt { get; set; }
+
+            public long JoinedAt { get; set; }
+
+            public PublicKey PlayerOneReferrer { get; set; }
+
+            public PublicKey PlayerTwoReferrer { get; set; }
+
+            public byte Version { get; set; }
+
+            public ulong PlayerOneDeposit { get; set; }
+
+            public ulong PlayerTwoDeposit { get; set; }
+
+            public RatingBand RatingBand { get; set; }
+
+            public PublicKey InvitedOpponent { get; set; }
+
+            public byte[] Reserved { get; set; }
+
             public static Battle Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -94,6 +206,218 @@ namespace ScoogiBattle
                 offset += 32;
                 result.BattleStatus = (BattleStatus)_data.GetU8(offset);
                 offset += 1;
+                result.SettlementMode = (SettlementMode)_data.GetU8(offset);
+                offset += 1;
+                result.Mint = _data.GetPubKey(offset);
+                offset += 32;
+                result.StakeAmount = _data.GetU64(offset);
+                offset += 8;
+                offset += FeeSchedule.Deserialize(_data, offset, out var resultFeeSchedule);
+                result.FeeSchedule = resultFeeSchedule;
+                result.TreasuryTokenAccount = _data.GetPubKey(offset);
+                offset += 32;
+                if (_data.GetBool(offset++))
+                {
+                    result.PlayerOneAttestation = _data.GetU8(offset);
+                    offset += 1;
+                }
+
+                if (_data.GetBool(offset++))
+                {
+                    result.PlayerTwoAttestation = _data.GetU8(offset);
+                    offset += 1;
+                }
+
+                result.DisputeDeadline = _data.GetS64(offset);
+                offset += 8;
+                result.CreatedAt = _data.GetS64(offset);
+                offset += 8;
+                result.JoinedAt = _data.GetS64(offset);
+                offset += 8;
+                result.PlayerOneReferrer = _data.GetPubKey(offset);
+                offset += 32;
+                result.PlayerTwoReferrer = _data.GetPubKey(offset);
+                offset += 32;
+                result.Version = _data.GetU8(offset);
+                offset += 1;
+                result.PlayerOneDeposit = _data.GetU64(offset);
+                offset += 8;
+                result.PlayerTwoDeposit = _data.GetU64(offset);
+                offset += 8;
+                if (_data.GetBool(offset++))
+                {
+                    offset += RatingBand.Deserialize(_data, offset, out var resultRatingBand);
+                    result.RatingBand = resultRatingBand;
+                }
+
+                result.InvitedOpponent = _data.GetPubKey(offset);
+                offset += 32;
+                result.Reserved = _data.GetBytes(offset, 7);
+                offset += 7;
+                return result;
+            }
+        }
+
+        public partial class MintConfig
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 3830256351642713256UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{168, 252, 88, 182, 219, 205, 39, 53};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "VGNecDKUeT6";
+            public PublicKey Mint { get; set; }
+
+            public ulong BattlePrice { get; set; }
+
+            public ulong MinStake { get; set; }
+
+            public ulong MaxStake { get; set; }
+
+            public FeeSchedule FeeSchedule { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public static MintConfig Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                MintConfig result = new MintConfig();
+                result.Mint = _data.GetPubKey(offset);
+                offset += 32;
+                result.BattlePrice = _data.GetU64(offset);
+                offset += 8;
+                result.MinStake = _data.GetU64(offset);
+                offset += 8;
+                result.MaxStake = _data.GetU64(offset);
+                offset += 8;
+                offset += FeeSchedule.Deserialize(_data, offset, out var resultFeeSchedule);
+                result.FeeSchedule = resultFeeSchedule;
+                result.TreasuryTokenAccount = _data.GetPubKey(offset);
+                offset += 32;
+                return result;
+            }
+        }
+
+        public partial class PendingMintConfig
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 4788874334862704096UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{224, 253, 121, 195, 213, 127, 117, 66};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "edgoKsJ3cYq";
+            public PublicKey Mint { get; set; }
+
+            public ulong BattlePrice { get; set; }
+
+            public ulong MinStake { get; set; }
+
+            public ulong MaxStake { get; set; }
+
+            public FeeSchedule FeeSchedule { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public bool Remove { get; set; }
+
+            public long EffectiveAt { get; set; }
+
+            public static PendingMintConfig Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                PendingMintConfig result = new PendingMintConfig();
+                result.Mint = _data.GetPubKey(offset);
+                offset += 32;
+                result.BattlePrice = _data.GetU64(offset);
+                offset += 8;
+                result.MinStake = _data.GetU64(offset);
+                offset += 8;
+                result.MaxStake = _data.GetU64(offset);
+                offset += 8;
+                offset += FeeSchedule.Deserialize(_data, offset, out var resultFeeSchedule);
+                result.FeeSchedule = resultFeeSchedule;
+                result.TreasuryTokenAccount = _data.GetPubKey(offset);
+                offset += 32;
+                result.Remove = _data.GetBool(offset);
+                offset += 1;
+                result.EffectiveAt = _data.GetS64(offset);
+                offset += 8;
+                return result;
+            }
+        }
+
+        public partial class PlayerProfile
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 5815698136171274834UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{82, 226, 99, 87, 164, 130, 181, 80};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "Es5kD8GxkuM";
+            public PublicKey Player { get; set; }
+
+            public ulong Wins { get; set; }
+
+            public ulong Losses { get; set; }
+
+            public byte[] Retired { get; set; }
+
+            public uint Rating { get; set; }
+
+            public byte Version { get; set; }
+
+            public byte[] Reserved { get; set; }
+
+            public static PlayerProfile Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                PlayerProfile result = new PlayerProfile();
+                result.Player = _data.GetPubKey(offset);
+                offset += 32;
+                result.Wins = _data.GetU64(offset);
+                offset += 8;
+                result.Losses = _data.GetU64(offset);
+                offset += 8;
+                result.Retired = _data.GetBytes(offset, 16);
+                offset += 16;
+                result.Rating = _data.GetU32(offset);
+                offset += 4;
+                result.Version = _data.GetU8(offset);
+                offset += 1;
+                result.Reserved = _data.GetBytes(offset, 64);
+                offset += 64;
+                return result;
+            }
+        }
+
+        public partial class SolVault
+        {
+            public static ulong ACCOUNT_DISCRIMINATOR => 17906823474679546901UL;
+            public static ReadOnlySpan<byte> ACCOUNT_DISCRIMINATOR_BYTES => new byte[]{21, 132, 230, 103, 19, 209, 129, 248};
+            public static string ACCOUNT_DISCRIMINATOR_B58 => "4bmETPzMVtB";
+            public static SolVault Deserialize(ReadOnlySpan<byte> _data)
+            {
+                int offset = 0;
+                ulong accountHashValue = _data.GetU64(offset);
+                offset += 8;
+                if (accountHashValue != ACCOUNT_DISCRIMINATOR)
+                {
+                    return null;
+                }
+
+                SolVault result = new SolVault();
                 return result;
             }
         }
@@ -108,17 +432,192 @@ namespace ScoogiBattle
             InvalidBattleStatus = 6002U,
             InvalidWithdrawal = 6003U,
             InternalError = 6004U,
-            Unauthorized = 6005U
+            Unauthorized = 6005U,
+            InvalidWinner = 6006U,
+            InvalidResultSignature = 6007U,
+            InvalidSettlementMode = 6008U,
+            AlreadyAttested = 6009U,
+            MissingAttestation = 6010U,
+            DisputeWindowOpen = 6011U,
+            DisputeWindowClosed = 6012U,
+            BattleNotExpired = 6013U,
+            FeeOutOfRange = 6014U,
+            InvalidReferrer = 6015U,
+            MissingReferrerAccount = 6016U,
+            PriceZero = 6017U,
+            PriceOverflow = 6018U,
+            StakeOutOfRange = 6019U,
+            MintNotAccepted = 6020U,
+            ProgramPaused = 6021U,
+            NoPendingConfig = 6022U,
+            ConfigTimelocked = 6023U,
+            InvalidRatingBand = 6024U,
+            RatingOutOfBand = 6025U,
+            InvalidOpponent = 6026U,
+            NotInvited = 6027U,
+            MintUnchanged = 6028U,
+            InvalidConfigDelay = 6029U,
+            InvalidDisputeWindow = 6030U,
+            MissingTreasury = 6031U
         }
     }
 
     namespace Types
     {
+        public partial class FeeSchedule
+        {
+            public ulong BurnFeeBps { get; set; }
+
+            public ulong TreasuryFeeBps { get; set; }
+
+            public ulong ReferrerFeeBps { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU64(BurnFeeBps, offset);
+                offset += 8;
+                _data.WriteU64(TreasuryFeeBps, offset);
+                offset += 8;
+                _data.WriteU64(ReferrerFeeBps, offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out FeeSchedule result)
+            {
+                int offset = initialOffset;
+                result = new FeeSchedule();
+                result.BurnFeeBps = _data.GetU64(offset);
+                offset += 8;
+                result.TreasuryFeeBps = _data.GetU64(offset);
+                offset += 8;
+                result.ReferrerFeeBps = _data.GetU64(offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
+        }
+
+        public partial class PendingConfig
+        {
+            public ulong? BattlePrice { get; set; }
+
+            public FeeSchedule FeeSchedule { get; set; }
+
+            public long EffectiveAt { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                if (BattlePrice != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteU64(BattlePrice.Value, offset);
+                    offset += 8;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                if (FeeSchedule != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    offset += FeeSchedule.Serialize(_data, offset);
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                _data.WriteS64(EffectiveAt, offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out PendingConfig result)
+            {
+                int offset = initialOffset;
+                result = new PendingConfig();
+                if (_data.GetBool(offset++))
+                {
+                    result.BattlePrice = _data.GetU64(offset);
+                    offset += 8;
+                }
+
+                if (_data.GetBool(offset++))
+                {
+                    offset += FeeSchedule.Deserialize(_data, offset, out var resultFeeSchedule);
+                    result.FeeSchedule = resultFeeSchedule;
+                }
+
+                result.EffectiveAt = _data.GetS64(offset);
+                offset += 8;
+                return offset - initialOffset;
+            }
+        }
+
+        public partial class RatingBand
+        {
+            public uint MinRating { get; set; }
+
+            public uint MaxRating { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU32(MinRating, offset);
+                offset += 4;
+                _data.WriteU32(MaxRating, offset);
+                offset += 4;
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out RatingBand result)
+            {
+                int offset = initialOffset;
+                result = new RatingBand();
+                result.MinRating = _data.GetU32(offset);
+                offset += 4;
+                result.MaxRating = _data.GetU32(offset);
+                offset += 4;
+                return offset - initialOffset;
+            }
+        }
+
+        public enum CancelReason : byte
+        {
+            Withdrawn,
+            Declined,
+            Expired,
+            Refunded,
+            AdminWithdrawn
+        }
+
+        public enum DisputeResolution : byte
+        {
+            PlayerOneWins,
+            PlayerTwoWins,
+            Refund
+        }
+
         public enum BattleStatus : byte
         {
             Pending,
             InProgress,
-            Completed
+            Completed,
+            Disputed,
+            ResultRecorded
+        }
+
+        public enum SettlementMode : byte
+        {
+            Oracle,
+            DualConfirmation
         }
     }
 
@@ -150,24 +649,104 @@ namespace ScoogiBattle
             return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<Battle>>(res, resultingAccounts);
         }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<Admin>> GetAdminAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<MintConfig>>> GetMintConfigsAsync(string programAddress, Commitment commitment = Commitment.Finalized)
         {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
-            if (!res.WasSuccessful)
-                return new Solana.Unity.Programs.Models.AccountResultWrapper<Admin>(res);
-            var resultingAccount = Admin.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
-            return new Solana.Unity.Programs.Models.AccountResultWrapper<Admin>(res, resultingAccount);
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = MintConfig.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<MintConfig>>(res);
+            List<MintConfig> resultingAccounts = new List<MintConfig>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => MintConfig.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<MintConfig>>(res, resultingAccounts);
         }
 
-        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<Battle>> GetBattleAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PendingMintConfig>>> GetPendingMintConfigsAsync(string programAddress, Commitment commitment = Commitment.Finalized)
         {
-            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = PendingMintConfig.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PendingMintConfig>>(res);
+            List<PendingMintConfig> resultingAccounts = new List<PendingMintConfig>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => PendingMintConfig.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PendingMintConfig>>(res, resultingAccounts);
+        }
+
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>> GetPlayerProfilesAsync(string programAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = PlayerProfile.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>(res);
+            List<PlayerProfile> resultingAccounts = new List<PlayerProfile>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => PlayerProfile.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<PlayerProfile>>(res, resultingAccounts);
+        }
+
+        public async Task<Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SolVault>>> GetSolVaultsAsync(string programAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var list = new List<Solana.Unity.Rpc.Models.MemCmp>{new Solana.Unity.Rpc.Models.MemCmp{Bytes = SolVault.ACCOUNT_DISCRIMINATOR_B58, Offset = 0}};
+            var res = await RpcClient.GetProgramAccountsAsync(programAddress, commitment, memCmpList: list);
+            if (!res.WasSuccessful || !(res.Result?.Count > 0))
+                return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SolVault>>(res);
+            List<SolVault> resultingAccounts = new List<SolVault>(res.Result.Count);
+            resultingAccounts.AddRange(res.Result.Select(result => SolVault.Deserialize(Convert.FromBase64String(result.Account.Data[0]))));
+            return new Solana.Unity.Programs.Models.ProgramAccountsResultWrapper<List<SolVault>>(res, resultingAccounts);
+        }
+
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<Admin>> GetAdminAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<Admin>(res);
+            var resultingAccount = Admin.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<Admin>(res, resultingAccount);
+        }
+
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<Battle>> GetBattleAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
             if (!res.WasSuccessful)
                 return new Solana.Unity.Programs.Models.AccountResultWrapper<Battle>(res);
             var resultingAccount = Battle.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
             return new Solana.Unity.Programs.Models.AccountResultWrapper<Battle>(res, resultingAccount);
         }
 
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<MintConfig>> GetMintConfigAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<MintConfig>(res);
+            var resultingAccount = MintConfig.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<MintConfig>(res, resultingAccount);
+        }
+
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<PendingMintConfig>> GetPendingMintConfigAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<PendingMintConfig>(res);
+            var resultingAccount = PendingMintConfig.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<PendingMintConfig>(res, resultingAccount);
+        }
+
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>> GetPlayerProfileAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>(res);
+            var resultingAccount = PlayerProfile.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<PlayerProfile>(res, resultingAccount);
+        }
+
+        public async Task<Solana.Unity.Programs.Models.AccountResultWrapper<SolVault>> GetSolVaultAsync(string accountAddress, Commitment commitment = Commitment.Finalized)
+        {
+            var res = await RpcClient.GetAccountInfoAsync(accountAddress, commitment);
+            if (!res.WasSuccessful)
+                return new Solana.Unity.Programs.Models.AccountResultWrapper<SolVault>(res);
+            var resultingAccount = SolVault.Deserialize(Convert.FromBase64String(res.Result.Value.Data[0]));
+            return new Solana.Unity.Programs.Models.AccountResultWrapper<SolVault>(res, resultingAccount);
+        }
+
         public async Task<SubscriptionState> SubscribeAdminAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, Admin> callback, Commitment commitment = Commitment.Finalized)
         {
             SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
@@ -192,180 +771,680 @@ namespace ScoogiBattle
             return res;
         }
 
+        public async Task<SubscriptionState> SubscribeMintConfigAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, MintConfig> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                MintConfig parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = MintConfig.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
+
+        public async Task<SubscriptionState> SubscribePendingMintConfigAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, PendingMintConfig> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                PendingMintConfig parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = PendingMintConfig.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
+
+        public async Task<SubscriptionState> SubscribePlayerProfileAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, PlayerProfile> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                PlayerProfile parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = PlayerProfile.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
+
+        public async Task<SubscriptionState> SubscribeSolVaultAsync(string accountAddress, Action<SubscriptionState, Solana.Unity.Rpc.Messages.ResponseValue<Solana.Unity.Rpc.Models.AccountInfo>, SolVault> callback, Commitment commitment = Commitment.Finalized)
+        {
+            SubscriptionState res = await StreamingRpcClient.SubscribeAccountInfoAsync(accountAddress, (s, e) =>
+            {
+                SolVault parsingResult = null;
+                if (e.Value?.Data?.Count > 0)
+                    parsingResult = SolVault.Deserialize(Convert.FromBase64String(e.Value.Data[0]));
+                callback(s, e, parsingResult);
+            }, commitment);
+            return res;
+        }
+
         public async Task<RequestResult<string>> SendInitializeAsync(InitializeAccounts accounts, ulong burnFeeBps, ulong battlePrice, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
             Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.Initialize(accounts, burnFeeBps, battlePrice, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public async Task<RequestResult<string>> SendUpdateBurnFeeBpsAsync(UpdateBurnFeeBpsAccounts accounts, ulong burnFeeBps, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        public async Task<RequestResult<string>> SendMigrateAdminAsync(MigrateAdminAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateBurnFeeBps(accounts, burnFeeBps, programId);
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.MigrateAdmin(accounts, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public async Task<RequestResult<string>> SendUpdateBattlePriceAsync(UpdateBattlePriceAccounts accounts, ulong battlePrice, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        public async Task<RequestResult<string>> SendMigrateBattleAsync(MigrateBattleAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateBattlePrice(accounts, battlePrice, programId);
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.MigrateBattle(accounts, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public async Task<RequestResult<string>> SendUpdateMintAsync(UpdateMintAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        public async Task<RequestResult<string>> SendMigratePlayerProfileAsync(MigratePlayerProfileAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateMint(accounts, programId);
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.MigratePlayerProfile(accounts, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public async Task<RequestResult<string>> SendAdminWithdrawalAsync(AdminWithdrawalAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        public async Task<RequestResult<string>> SendProposeAdminAsync(ProposeAdminAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.AdminWithdrawal(accounts, battleId, programId);
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.ProposeAdmin(accounts, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public async Task<RequestResult<string>> SendCreateBattleAsync(CreateBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        public async Task<RequestResult<string>> SendCancelAdminTransferAsync(CancelAdminTransferAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.CreateBattle(accounts, battleId, programId);
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.CancelAdminTransfer(accounts, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public async Task<RequestResult<string>> SendJoinBattleAsync(JoinBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        public async Task<RequestResult<string>> SendAcceptAdminAsync(AcceptAdminAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.JoinBattle(accounts, battleId, programId);
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.AcceptAdmin(accounts, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public async Task<RequestResult<string>> SendRecordBattleResultAsync(RecordBattleResultAccounts accounts, byte battleResult, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        public async Task<RequestResult<string>> SendUpdateRolesAsync(UpdateRolesAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.RecordBattleResult(accounts, battleResult, battleId, programId);
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateRoles(accounts, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public async Task<RequestResult<string>> SendWithdrawFromBattleAsync(WithdrawFromBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        public async Task<RequestResult<string>> SendSetPausedAsync(SetPausedAccounts accounts, byte flags, bool paused, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.WithdrawFromBattle(accounts, battleId, programId);
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.SetPaused(accounts, flags, paused, programId);
             return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        protected override Dictionary<uint, ProgramError<ScoogiBattleErrorKind>> BuildErrorsDictionary()
+        public async Task<RequestResult<string>> SendUpdateBurnFeeBpsAsync(UpdateBurnFeeBpsAccounts accounts, ulong burnFeeBps, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            return new Dictionary<uint, ProgramError<ScoogiBattleErrorKind>>{{6000U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidBattleResult, "Invalid battle result")}, {6001U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidBattleId, "Invalid battle id")}, {6002U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidBattleStatus, "Invalid battle status")}, {6003U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidWithdrawal, "Invalid withdrawal")}, {6004U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InternalError, "Internal error")}, {6005U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.Unauthorized, "Unauthorized")}, };
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateBurnFeeBps(accounts, burnFeeBps, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
-    }
 
-    namespace Program
-    {
-        public class InitializeAccounts
+        public async Task<RequestResult<string>> SendUpdateFeeScheduleAsync(UpdateFeeScheduleAccounts accounts, FeeSchedule feeSchedule, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            public PublicKey Admin { get; set; }
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateFeeSchedule(accounts, feeSchedule, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey AdminAccount { get; set; }
+        public async Task<RequestResult<string>> SendUpdateTreasuryAsync(UpdateTreasuryAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateTreasury(accounts, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey Mint { get; set; }
+        public async Task<RequestResult<string>> SendUpdateBattlePriceAsync(UpdateBattlePriceAccounts accounts, ulong battlePrice, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateBattlePrice(accounts, battlePrice, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey SystemProgram { get; set; }
+        public async Task<RequestResult<string>> SendUpdateStakeBoundsAsync(UpdateStakeBoundsAccounts accounts, ulong minStake, ulong maxStake, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateStakeBounds(accounts, minStake, maxStake, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public class UpdateBurnFeeBpsAccounts
+        public async Task<RequestResult<string>> SendUpdateConfigDelayAsync(UpdateConfigDelayAccounts accounts, long configDelaySeconds, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            public PublicKey Admin { get; set; }
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateConfigDelay(accounts, configDelaySeconds, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey AdminAccount { get; set; }
+        public async Task<RequestResult<string>> SendApplyConfigAsync(ApplyConfigAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.ApplyConfig(accounts, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey SystemProgram { get; set; }
+        public async Task<RequestResult<string>> SendUpdateMintAsync(UpdateMintAccounts accounts, ulong battlePrice, ulong minStake, ulong maxStake, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateMint(accounts, battlePrice, minStake, maxStake, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public class UpdateBattlePriceAccounts
+        public async Task<RequestResult<string>> SendAddMintConfigAsync(AddMintConfigAccounts accounts, ulong battlePrice, ulong minStake, ulong maxStake, FeeSchedule feeSchedule, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            public PublicKey Admin { get; set; }
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.AddMintConfig(accounts, battlePrice, minStake, maxStake, feeSchedule, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey AdminAccount { get; set; }
+        public async Task<RequestResult<string>> SendApplyMintConfigAsync(ApplyMintConfigAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.ApplyMintConfig(accounts, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey Mint { get; set; }
+        public async Task<RequestResult<string>> SendRemoveMintConfigAsync(RemoveMintConfigAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.RemoveMintConfig(accounts, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public class UpdateMintAccounts
+        public async Task<RequestResult<string>> SendAddSolConfigAsync(AddSolConfigAccounts accounts, ulong battlePrice, ulong minStake, ulong maxStake, FeeSchedule feeSchedule, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            public PublicKey Admin { get; set; }
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.AddSolConfig(accounts, battlePrice, minStake, maxStake, feeSchedule, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey AdminAccount { get; set; }
+        public async Task<RequestResult<string>> SendUpdateResultAuthorityAsync(UpdateResultAuthorityAccounts accounts, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateResultAuthority(accounts, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey Mint { get; set; }
+        public async Task<RequestResult<string>> SendUpdateDisputeWindowAsync(UpdateDisputeWindowAccounts accounts, long disputeWindowSeconds, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateDisputeWindow(accounts, disputeWindowSeconds, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public class AdminWithdrawalAccounts
+        public async Task<RequestResult<string>> SendUpdateBattleTimeoutAsync(UpdateBattleTimeoutAccounts accounts, long battleTimeoutSeconds, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            public PublicKey Admin { get; set; }
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.UpdateBattleTimeout(accounts, battleTimeoutSeconds, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey PlayerOne { get; set; }
+        public async Task<RequestResult<string>> SendAdminWithdrawalAsync(AdminWithdrawalAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.AdminWithdrawal(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey PlayerTwo { get; set; }
+        public async Task<RequestResult<string>> SendAdminSolWithdrawalAsync(AdminSolWithdrawalAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.AdminSolWithdrawal(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey AdminAccount { get; set; }
+        public async Task<RequestResult<string>> SendCreateBattleAsync(CreateBattleAccounts accounts, ulong battleId, SettlementMode settlementMode, PublicKey referrer, ulong? stake, RatingBand ratingBand, PublicKey invitedOpponent, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.CreateBattle(accounts, battleId, settlementMode, referrer, stake, ratingBand, invitedOpponent, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey BattleAccount { get; set; }
+        public async Task<RequestResult<string>> SendJoinBattleAsync(JoinBattleAccounts accounts, ulong battleId, PublicKey referrer, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.JoinBattle(accounts, battleId, referrer, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey BattleTokenAccount { get; set; }
+        public async Task<RequestResult<string>> SendRecordBattleResultAsync(RecordBattleResultAccounts accounts, byte battleResult, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.RecordBattleResult(accounts, battleResult, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey PlayerOneTokenAccount { get; set; }
+        public async Task<RequestResult<string>> SendAttestBattleResultAsync(AttestBattleResultAccounts accounts, ulong battleId, byte battleResult, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.AttestBattleResult(accounts, battleId, battleResult, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey PlayerTwoTokenAccount { get; set; }
+        public async Task<RequestResult<string>> SendDisputeBattleResultAsync(DisputeBattleResultAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.DisputeBattleResult(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey Mint { get; set; }
+        public async Task<RequestResult<string>> SendFinalizeBattleAsync(FinalizeBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.FinalizeBattle(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey SystemProgram { get; set; }
+        public async Task<RequestResult<string>> SendResolveDisputeAsync(ResolveDisputeAccounts accounts, ulong battleId, DisputeResolution resolution, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.ResolveDispute(accounts, battleId, resolution, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey AssociatedTokenProgram { get; set; }
+        public async Task<RequestResult<string>> SendExpireBattleAsync(ExpireBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.ExpireBattle(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey TokenProgram { get; set; }
+        public async Task<RequestResult<string>> SendWithdrawFromBattleAsync(WithdrawFromBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.WithdrawFromBattle(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public class CreateBattleAccounts
+        public async Task<RequestResult<string>> SendDeclineBattleAsync(DeclineBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
         {
-            public PublicKey PlayerOne { get; set; }
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.DeclineBattle(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey AdminAccount { get; set; }
+        public async Task<RequestResult<string>> SendCreateSolBattleAsync(CreateSolBattleAccounts accounts, ulong battleId, SettlementMode settlementMode, PublicKey referrer, ulong? stake, RatingBand ratingBand, PublicKey invitedOpponent, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.CreateSolBattle(accounts, battleId, settlementMode, referrer, stake, ratingBand, invitedOpponent, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey BattleAccount { get; set; }
+        public async Task<RequestResult<string>> SendJoinSolBattleAsync(JoinSolBattleAccounts accounts, ulong battleId, PublicKey referrer, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.JoinSolBattle(accounts, battleId, referrer, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey PlayerOneTokenAccount { get; set; }
+        public async Task<RequestResult<string>> SendRecordSolBattleResultAsync(RecordSolBattleResultAccounts accounts, byte battleResult, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.RecordSolBattleResult(accounts, battleResult, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey BattleTokenAccount { get; set; }
+        public async Task<RequestResult<string>> SendFinalizeSolBattleAsync(FinalizeSolBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.FinalizeSolBattle(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey Mint { get; set; }
+        public async Task<RequestResult<string>> SendResolveSolDisputeAsync(ResolveSolDisputeAccounts accounts, ulong battleId, DisputeResolution resolution, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.ResolveSolDispute(accounts, battleId, resolution, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey SystemProgram { get; set; }
+        public async Task<RequestResult<string>> SendExpireSolBattleAsync(ExpireSolBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.ExpireSolBattle(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey AssociatedTokenProgram { get; set; }
+        public async Task<RequestResult<string>> SendWithdrawFromSolBattleAsync(WithdrawFromSolBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.WithdrawFromSolBattle(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
+        }
 
-            public PublicKey TokenProgram { get; set; }
+        public async Task<RequestResult<string>> SendDeclineSolBattleAsync(DeclineSolBattleAccounts accounts, ulong battleId, PublicKey feePayer, Func<byte[], PublicKey, byte[]> signingCallback, PublicKey programId)
+        {
+            Solana.Unity.Rpc.Models.TransactionInstruction instr = Program.ScoogiBattleProgram.DeclineSolBattle(accounts, battleId, programId);
+            return await SignAndSendTransaction(instr, feePayer, signingCallback);
         }
 
-        public class JoinBattleAccounts
+        protected override Dictionary<uint, ProgramError<ScoogiBattleErrorKind>> BuildErrorsDictionary()
         {
-            public PublicKey PlayerTwo { get; set; }
+            return new Dictionary<uint, ProgramError<ScoogiBattleErrorKind>>{{6000U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidBattleResult, "Invalid battle result")}, {6001U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidBattleId, "Invalid battle id")}, {6002U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidBattleStatus, "Invalid battle status")}, {6003U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidWithdrawal, "Invalid withdrawal")}, {6004U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InternalError, "Internal error")}, {6005U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.Unauthorized, "Unauthorized")}, {6006U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidWinner, "Winner does not match the battle result")}, {6007U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidResultSignature, "Invalid result signature")}, {6008U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidSettlementMode, "Invalid settlement mode")}, {6009U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.AlreadyAttested, "Result already attested")}, {6010U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.MissingAttestation, "Both players must attest the same result")}, {6011U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.DisputeWindowOpen, "Dispute window is still open")}, {6012U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.DisputeWindowClosed, "Dispute window has closed")}, {6013U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.BattleNotExpired, "Battle has not expired")}, {6014U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.FeeOutOfRange, "Fee is out of range")}, {6015U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidReferrer, "Invalid referrer")}, {6016U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.MissingReferrerAccount, "Referrer token account is missing")}, {6017U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.PriceZero, "Price must be greater than zero")}, {6018U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.PriceOverflow, "Price overflows the mint's base units")}, {6019U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.StakeOutOfRange, "Stake is out of range")}, {6020U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.MintNotAccepted, "Mint is not accepted for wagers")}, {6021U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.ProgramPaused, "Program is paused")}, {6022U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.NoPendingConfig, "No config change is queued")}, {6023U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.ConfigTimelocked, "Config change is still timelocked")}, {6024U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidRatingBand, "Rating band minimum is above its maximum")}, {6025U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.RatingOutOfBand, "Challenger rating is outside the battle's rating band")}, {6026U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidOpponent, "Player can't be invited to their own battle")}, {6027U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.NotInvited, "Battle is reserved for its invited opponent")}, {6028U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.MintUnchanged, "Mint is already the admin's mint")}, {6029U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidConfigDelay, "Config delay is above the maximum")}, {6030U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.InvalidDisputeWindow, "Dispute window is above the maximum")}, {6031U, new ProgramError<ScoogiBattleErrorKind>(ScoogiBattleErrorKind.MissingTreasury, "Treasury is not set")}, };
+        }
+    }
 
-            public PublicKey PlayerOne { get; set; }
+    namespace Program
+    {
+        public class InitializeAccounts
+        {
+            public PublicKey Admin { get; set; }
 
             public PublicKey AdminAccount { get; set; }
 
-            public PublicKey BattleAccount { get; set; }
-
-            public PublicKey PlayerTwoTokenAccount { get; set; }
+            public PublicKey Mint { get; set; }
 
-            public PublicKey BattleTokenAccount { get; set; }
+            public PublicKey TreasuryTokenAccount { get; set; }
 
-            public PublicKey Mint { get; set; }
+            public PublicKey ResultAuthority { get; set; }
 
             public PublicKey SystemProgram { get; set; }
+        }
 
-            public PublicKey AssociatedTokenProgram { get; set; }
+        public class MigrateAdminAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public PublicKey ResultAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+        }
+
+        public class MigrateBattleAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+        }
+
+        public class MigratePlayerProfileAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey PlayerProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+        }
+
+        public class ProposeAdminAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey PendingAdmin { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class CancelAdminTransferAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class AcceptAdminAccounts
+        {
+            public PublicKey PendingAdmin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateRolesAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey TreasuryAuthority { get; set; }
+
+            public PublicKey Pauser { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class SetPausedAccounts
+        {
+            public PublicKey Pauser { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateBurnFeeBpsAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateFeeScheduleAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateTreasuryAccounts
+        {
+            public PublicKey TreasuryAuthority { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateBattlePriceAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateStakeBoundsAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateConfigDelayAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class ApplyConfigAccounts
+        {
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateMintAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class AddMintConfigAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey PendingMintConfig { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class ApplyMintConfigAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey PendingMintConfig { get; set; }
+
+            public PublicKey MintConfig { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class RemoveMintConfigAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey MintConfig { get; set; }
+
+            public PublicKey PendingMintConfig { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class AddSolConfigAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey PendingMintConfig { get; set; }
+
+            public PublicKey Treasury { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateResultAuthorityAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey ResultAuthority { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateDisputeWindowAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class UpdateBattleTimeoutAccounts
+        {
+            public PublicKey ConfigManager { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class AdminWithdrawalAccounts
+        {
+            public PublicKey TreasuryAuthority { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey BattleTokenAccount { get; set; }
+
+            public PublicKey PlayerOneTokenAccount { get; set; }
+
+            public PublicKey PlayerTwoTokenAccount { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
 
             public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
         }
 
-        public class RecordBattleResultAccounts
+        public class AdminSolWithdrawalAccounts
         {
-            public PublicKey Winner { get; set; }
+            public PublicKey TreasuryAuthority { get; set; }
 
             public PublicKey PlayerOne { get; set; }
 
@@ -375,131 +1454,1250 @@ namespace ScoogiBattle
 
             public PublicKey AdminAccount { get; set; }
 
-            public PublicKey AdminTokenAccount { get; set; }
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey Treasury { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class CreateBattleAccounts
+        {
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey MintConfig { get; set; }
 
             public PublicKey BattleAccount { get; set; }
 
+            public PublicKey PlayerOneTokenAccount { get; set; }
+
             public PublicKey BattleTokenAccount { get; set; }
 
-            public PublicKey WinnerTokenAccount { get; set; }
+            public PublicKey Mint { get; set; }
+
+            public PublicKey PlayerOneProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
+
+            public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class JoinBattleAccounts
+        {
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey PlayerTwoTokenAccount { get; set; }
+
+            public PublicKey BattleTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey PlayerTwoProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
+
+            public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class RecordBattleResultAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey ResultAuthority { get; set; }
+
+            public PublicKey Winner { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey BattleTokenAccount { get; set; }
+
+            public PublicKey WinnerTokenAccount { get; set; }
+
+            public PublicKey PlayerOneReferrerTokenAccount { get; set; }
+
+            public PublicKey PlayerTwoReferrerTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey InstructionsSysvar { get; set; }
+
+            public PublicKey PlayerOneProfile { get; set; }
+
+            public PublicKey PlayerTwoProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
+
+            public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class AttestBattleResultAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class DisputeBattleResultAccounts
+        {
+            public PublicKey Player { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class FinalizeBattleAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey Winner { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey BattleTokenAccount { get; set; }
+
+            public PublicKey WinnerTokenAccount { get; set; }
+
+            public PublicKey PlayerOneReferrerTokenAccount { get; set; }
+
+            public PublicKey PlayerTwoReferrerTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey PlayerOneProfile { get; set; }
+
+            public PublicKey PlayerTwoProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
+
+            public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class ResolveDisputeAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey TreasuryTokenAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey BattleTokenAccount { get; set; }
+
+            public PublicKey PlayerOneTokenAccount { get; set; }
+
+            public PublicKey PlayerTwoTokenAccount { get; set; }
+
+            public PublicKey PlayerOneReferrerTokenAccount { get; set; }
+
+            public PublicKey PlayerTwoReferrerTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey PlayerOneProfile { get; set; }
+
+            public PublicKey PlayerTwoProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
+
+            public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class ExpireBattleAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey BattleTokenAccount { get; set; }
+
+            public PublicKey PlayerOneTokenAccount { get; set; }
+
+            public PublicKey PlayerTwoTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
+
+            public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class WithdrawFromBattleAccounts
+        {
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey BattleTokenAccount { get; set; }
+
+            public PublicKey PlayerOneTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
+
+            public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class DeclineBattleAccounts
+        {
+            public PublicKey InvitedOpponent { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey BattleTokenAccount { get; set; }
+
+            public PublicKey PlayerOneTokenAccount { get; set; }
+
+            public PublicKey Mint { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey AssociatedTokenProgram { get; set; }
+
+            public PublicKey TokenProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class CreateSolBattleAccounts
+        {
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey MintConfig { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey PlayerOneProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class JoinSolBattleAccounts
+        {
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey PlayerTwoProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class RecordSolBattleResultAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey ResultAuthority { get; set; }
+
+            public PublicKey Winner { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey Treasury { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey PlayerOneReferrer { get; set; }
+
+            public PublicKey PlayerTwoReferrer { get; set; }
+
+            public PublicKey Incinerator { get; set; }
+
+            public PublicKey InstructionsSysvar { get; set; }
+
+            public PublicKey PlayerOneProfile { get; set; }
+
+            public PublicKey PlayerTwoProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class FinalizeSolBattleAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey Winner { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey Treasury { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey PlayerOneReferrer { get; set; }
+
+            public PublicKey PlayerTwoReferrer { get; set; }
+
+            public PublicKey Incinerator { get; set; }
+
+            public PublicKey PlayerOneProfile { get; set; }
+
+            public PublicKey PlayerTwoProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class ResolveSolDisputeAccounts
+        {
+            public PublicKey Admin { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey Treasury { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey PlayerOneReferrer { get; set; }
+
+            public PublicKey PlayerTwoReferrer { get; set; }
+
+            public PublicKey Incinerator { get; set; }
+
+            public PublicKey PlayerOneProfile { get; set; }
+
+            public PublicKey PlayerTwoProfile { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class ExpireSolBattleAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey PlayerTwo { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class WithdrawFromSolBattleAccounts
+        {
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public class DeclineSolBattleAccounts
+        {
+            public PublicKey InvitedOpponent { get; set; }
+
+            public PublicKey PlayerOne { get; set; }
+
+            public PublicKey Admin { get; set; }
+
+            public PublicKey AdminAccount { get; set; }
+
+            public PublicKey BattleAccount { get; set; }
+
+            public PublicKey SolVault { get; set; }
+
+            public PublicKey SystemProgram { get; set; }
+
+            public PublicKey EventAuthority { get; set; }
+
+            public PublicKey Program { get; set; }
+        }
+
+        public static class ScoogiBattleProgram
+        {
+            public static Solana.Unity.Rpc.Models.TransactionInstruction Initialize(InitializeAccounts accounts, ulong burnFeeBps, ulong battlePrice, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ResultAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(17121445590508351407UL, offset);
+                offset += 8;
+                _data.WriteU64(burnFeeBps, offset);
+                offset += 8;
+                _data.WriteU64(battlePrice, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MigrateAdmin(MigrateAdminAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ResultAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(8712027257246096247UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MigrateBattle(MigrateBattleAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(9888580993488498513UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MigratePlayerProfile(MigratePlayerProfileAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(18056328648060335152UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ProposeAdmin(ProposeAdminAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PendingAdmin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(16894452835335788153UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction CancelAdminTransfer(CancelAdminTransferAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(15504919281545347878UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AcceptAdmin(AcceptAdminAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingAdmin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(12253649672478730864UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateRoles(UpdateRolesAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ConfigManager, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TreasuryAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Pauser, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(9068978278702618844UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetPaused(SetPausedAccounts accounts, byte flags, bool paused, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Pauser, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(15755528495662185563UL, offset);
+                offset += 8;
+                _data.WriteU8(flags, offset);
+                offset += 1;
+                _data.WriteBool(paused, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateBurnFeeBps(UpdateBurnFeeBpsAccounts accounts, ulong burnFeeBps, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(3150535225490043282UL, offset);
+                offset += 8;
+                _data.WriteU64(burnFeeBps, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateFeeSchedule(UpdateFeeScheduleAccounts accounts, FeeSchedule feeSchedule, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(5901445655579682750UL, offset);
+                offset += 8;
+                offset += feeSchedule.Serialize(_data, offset);
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateTreasury(UpdateTreasuryAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TreasuryAuthority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(9511104747679191100UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateBattlePrice(UpdateBattlePriceAccounts accounts, ulong battlePrice, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7564932090806838066UL, offset);
+                offset += 8;
+                _data.WriteU64(battlePrice, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateStakeBounds(UpdateStakeBoundsAccounts accounts, ulong minStake, ulong maxStake, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(4618869959356670359UL, offset);
+                offset += 8;
+                _data.WriteU64(minStake, offset);
+                offset += 8;
+                _data.WriteU64(maxStake, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateConfigDelay(UpdateConfigDelayAccounts accounts, long configDelaySeconds, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(1914604368914271625UL, offset);
+                offset += 8;
+                _data.WriteS64(configDelaySeconds, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyConfig(ApplyConfigAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(17972060933923875449UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateMint(UpdateMintAccounts accounts, ulong battlePrice, ulong minStake, ulong maxStake, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(423045118803168212UL, offset);
+                offset += 8;
+                _data.WriteU64(battlePrice, offset);
+                offset += 8;
+                _data.WriteU64(minStake, offset);
+                offset += 8;
+                _data.WriteU64(maxStake, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AddMintConfig(AddMintConfigAccounts accounts, ulong battlePrice, ulong minStake, ulong maxStake, FeeSchedule feeSchedule, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingMintConfig, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(5586938648440226044UL, offset);
+                offset += 8;
+                _data.WriteU64(battlePrice, offset);
+                offset += 8;
+                _data.WriteU64(minStake, offset);
+                offset += 8;
+                _data.WriteU64(maxStake, offset);
+                offset += 8;
+                offset += feeSchedule.Serialize(_data, offset);
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyMintConfig(ApplyMintConfigAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingMintConfig, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.MintConfig, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(6758908497337819760UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction RemoveMintConfig(RemoveMintConfigAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.MintConfig, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingMintConfig, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7374411501449094154UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AddSolConfig(AddSolConfigAccounts accounts, ulong battlePrice, ulong minStake, ulong maxStake, FeeSchedule feeSchedule, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PendingMintConfig, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Treasury, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(14201085876849280961UL, offset);
+                offset += 8;
+                _data.WriteU64(battlePrice, offset);
+                offset += 8;
+                _data.WriteU64(minStake, offset);
+                offset += 8;
+                _data.WriteU64(maxStake, offset);
+                offset += 8;
+                offset += feeSchedule.Serialize(_data, offset);
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateResultAuthority(UpdateResultAuthorityAccounts accounts, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ResultAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(14266670346823454978UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateDisputeWindow(UpdateDisputeWindowAccounts accounts, long disputeWindowSeconds, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(10108418760801532825UL, offset);
+                offset += 8;
+                _data.WriteS64(disputeWindowSeconds, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey Mint { get; set; }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateBattleTimeout(UpdateBattleTimeoutAccounts accounts, long battleTimeoutSeconds, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.ConfigManager, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(2061956206065070953UL, offset);
+                offset += 8;
+                _data.WriteS64(battleTimeoutSeconds, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey SystemProgram { get; set; }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AdminWithdrawal(AdminWithdrawalAccounts accounts, ulong battleId, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TreasuryAuthority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoTokenAccount == null ? programId : accounts.PlayerTwoTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(10791029715833818358UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey AssociatedTokenProgram { get; set; }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AdminSolWithdrawal(AdminSolWithdrawalAccounts accounts, ulong battleId, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TreasuryAuthority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwo == null ? programId : accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Treasury, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(3688197486876010003UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey TokenProgram { get; set; }
-        }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction CreateBattle(CreateBattleAccounts accounts, ulong battleId, SettlementMode settlementMode, PublicKey referrer, ulong? stake, RatingBand ratingBand, PublicKey invitedOpponent, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.MintConfig == null ? programId : accounts.MintConfig, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7402619448180799746UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                _data.WriteU8((byte)settlementMode, offset);
+                offset += 1;
+                if (referrer != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WritePubKey(referrer, offset);
+                    offset += 32;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
 
-        public class WithdrawFromBattleAccounts
-        {
-            public PublicKey PlayerOne { get; set; }
+                if (stake != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteU64(stake.Value, offset);
+                    offset += 8;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
 
-            public PublicKey Admin { get; set; }
+                if (ratingBand != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    offset += ratingBand.Serialize(_data, offset);
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
 
-            public PublicKey AdminAccount { get; set; }
+                if (invitedOpponent != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WritePubKey(invitedOpponent, offset);
+                    offset += 32;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
 
-            public PublicKey BattleAccount { get; set; }
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey BattleTokenAccount { get; set; }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinBattle(JoinBattleAccounts accounts, ulong battleId, PublicKey referrer, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwo, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7221119029367603326UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                if (referrer != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WritePubKey(referrer, offset);
+                    offset += 32;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
 
-            public PublicKey PlayerOneTokenAccount { get; set; }
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey Mint { get; set; }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction RecordBattleResult(RecordBattleResultAccounts accounts, byte battleResult, ulong battleId, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ResultAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Winner, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.WinnerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneReferrerTokenAccount == null ? programId : accounts.PlayerOneReferrerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoReferrerTokenAccount == null ? programId : accounts.PlayerTwoReferrerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.InstructionsSysvar, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneProfile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(15787151554137610846UL, offset);
+                offset += 8;
+                _data.WriteU8(battleResult, offset);
+                offset += 1;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey SystemProgram { get; set; }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AttestBattleResult(AttestBattleResultAccounts accounts, ulong battleId, byte battleResult, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7072129459162475844UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                _data.WriteU8(battleResult, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey AssociatedTokenProgram { get; set; }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction DisputeBattleResult(DisputeBattleResultAccounts accounts, ulong battleId, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Player, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(18086755876641934098UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-            public PublicKey TokenProgram { get; set; }
-        }
+            public static Solana.Unity.Rpc.Models.TransactionInstruction FinalizeBattle(FinalizeBattleAccounts accounts, ulong battleId, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Winner, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.WinnerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneReferrerTokenAccount == null ? programId : accounts.PlayerOneReferrerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoReferrerTokenAccount == null ? programId : accounts.PlayerTwoReferrerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneProfile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(16518502731704433769UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
 
-        public static class ScoogiBattleProgram
-        {
-            public static Solana.Unity.Rpc.Models.TransactionInstruction Initialize(InitializeAccounts accounts, ulong burnFeeBps, ulong battlePrice, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ResolveDispute(ResolveDisputeAccounts accounts, ulong battleId, DisputeResolution resolution, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.TreasuryTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneReferrerTokenAccount == null ? programId : accounts.PlayerOneReferrerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoReferrerTokenAccount == null ? programId : accounts.PlayerTwoReferrerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneProfile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(17121445590508351407UL, offset);
+                _data.WriteU64(16576737990572377831UL, offset);
                 offset += 8;
-                _data.WriteU64(burnFeeBps, offset);
+                _data.WriteU64(battleId, offset);
                 offset += 8;
-                _data.WriteU64(battlePrice, offset);
+                _data.WriteU8((byte)resolution, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ExpireBattle(ExpireBattleAccounts accounts, ulong battleId, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoTokenAccount == null ? programId : accounts.PlayerTwoTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(5132638351306038119UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateBurnFeeBps(UpdateBurnFeeBpsAccounts accounts, ulong burnFeeBps, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction WithdrawFromBattle(WithdrawFromBattleAccounts accounts, ulong battleId, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(3150535225490043282UL, offset);
+                _data.WriteU64(10978602512387980774UL, offset);
                 offset += 8;
-                _data.WriteU64(burnFeeBps, offset);
+                _data.WriteU64(battleId, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateBattlePrice(UpdateBattlePriceAccounts accounts, ulong battlePrice, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction DeclineBattle(DeclineBattleAccounts accounts, ulong battleId, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.InvitedOpponent, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(7564932090806838066UL, offset);
+                _data.WriteU64(11249610176143873714UL, offset);
                 offset += 8;
-                _data.WriteU64(battlePrice, offset);
+                _data.WriteU64(battleId, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction UpdateMint(UpdateMintAccounts accounts, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction CreateSolBattle(CreateSolBattleAccounts accounts, ulong battleId, SettlementMode settlementMode, PublicKey referrer, ulong? stake, RatingBand ratingBand, PublicKey invitedOpponent, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.MintConfig, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(423045118803168212UL, offset);
+                _data.WriteU64(8701256738129026406UL, offset);
                 offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                _data.WriteU8((byte)settlementMode, offset);
+                offset += 1;
+                if (referrer != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WritePubKey(referrer, offset);
+                    offset += 32;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                if (stake != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteU64(stake.Value, offset);
+                    offset += 8;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                if (ratingBand != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    offset += ratingBand.Serialize(_data, offset);
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                if (invitedOpponent != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WritePubKey(invitedOpponent, offset);
+                    offset += 32;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction AdminWithdrawal(AdminWithdrawalAccounts accounts, ulong battleId, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinSolBattle(JoinSolBattleAccounts accounts, ulong battleId, PublicKey referrer, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwo, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(10791029715833818358UL, offset);
+                _data.WriteU64(14073254975896848218UL, offset);
                 offset += 8;
                 _data.WriteU64(battleId, offset);
                 offset += 8;
+                if (referrer != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WritePubKey(referrer, offset);
+                    offset += 32;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction CreateBattle(CreateBattleAccounts accounts, ulong battleId, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction RecordSolBattleResult(RecordSolBattleResultAccounts accounts, byte battleResult, ulong battleId, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ResultAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Winner, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Treasury, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneReferrer == null ? programId : accounts.PlayerOneReferrer, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoReferrer == null ? programId : accounts.PlayerTwoReferrer, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Incinerator, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.InstructionsSysvar, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneProfile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(7402619448180799746UL, offset);
+                _data.WriteU64(4722745332700806854UL, offset);
                 offset += 8;
+                _data.WriteU8(battleResult, offset);
+                offset += 1;
                 _data.WriteU64(battleId, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
@@ -507,13 +2705,13 @@ namespace ScoogiBattle
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction JoinBattle(JoinBattleAccounts accounts, ulong battleId, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction FinalizeSolBattle(FinalizeSolBattleAccounts accounts, ulong battleId, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwo, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Winner, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Treasury, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneReferrer == null ? programId : accounts.PlayerOneReferrer, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoReferrer == null ? programId : accounts.PlayerTwoReferrer, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Incinerator, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneProfile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(7221119029367603326UL, offset);
+                _data.WriteU64(6302655904319225029UL, offset);
                 offset += 8;
                 _data.WriteU64(battleId, offset);
                 offset += 8;
@@ -522,16 +2720,31 @@ namespace ScoogiBattle
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction RecordBattleResult(RecordBattleResultAccounts accounts, byte battleResult, ulong battleId, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ResolveSolDispute(ResolveSolDisputeAccounts accounts, ulong battleId, DisputeResolution resolution, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Winner, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.AdminTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.WinnerTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Treasury, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneReferrer == null ? programId : accounts.PlayerOneReferrer, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoReferrer == null ? programId : accounts.PlayerTwoReferrer, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Incinerator, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneProfile, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwoProfile, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(15787151554137610846UL, offset);
+                _data.WriteU64(10020575746662466752UL, offset);
                 offset += 8;
-                _data.WriteU8(battleResult, offset);
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                _data.WriteU8((byte)resolution, offset);
                 offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ExpireSolBattle(ExpireSolBattleAccounts accounts, ulong battleId, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerTwo == null ? programId : accounts.PlayerTwo, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(12364889780701461080UL, offset);
+                offset += 8;
                 _data.WriteU64(battleId, offset);
                 offset += 8;
                 byte[] resultData = new byte[offset];
@@ -539,13 +2752,28 @@ namespace ScoogiBattle
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction WithdrawFromBattle(WithdrawFromBattleAccounts accounts, ulong battleId, PublicKey programId)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction WithdrawFromSolBattle(WithdrawFromSolBattleAccounts accounts, ulong battleId, PublicKey programId)
             {
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOneTokenAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Mint, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AssociatedTokenProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.TokenProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
-                _data.WriteU64(10978602512387980774UL, offset);
+                _data.WriteU64(1527647967475201552UL, offset);
+                offset += 8;
+                _data.WriteU64(battleId, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction DeclineSolBattle(DeclineSolBattleAccounts accounts, ulong battleId, PublicKey programId)
+            {
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.InvitedOpponent, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.PlayerOne, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Admin, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AdminAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.BattleAccount, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.SolVault, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.EventAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Program, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(14089749366735820583UL, offset);
                 offset += 8;
                 _data.WriteU64(battleId, offset);
                 offset += 8;
@@ -555,4 +2783,28 @@ namespace ScoogiBattle
             }
         }
     }
+
+    public static class ScoogiBattleMessages
+    {
+        // The message the result authority signs with Ed25519 so anyone can relay a
+        // `RecordBattleResult` settlement on its behalf, byte for byte the same as
+        // `getBattleResultMessage` in anchor/src/scoogi-battle-exports.ts.
+        public static byte[] BattleResult(PublicKey programId, ulong battleId, long createdAt, PublicKey playerOne, PublicKey playerTwo, PublicKey winner)
+        {
+            byte[] _data = new byte[32 + 8 + 8 + 32 + 32 + 32];
+            int offset = 0;
+            _data.WritePubKey(programId, offset);
+            offset += 32;
+            _data.WriteU64(battleId, offset);
+            offset += 8;
+            _data.WriteS64(createdAt, offset);
+            offset += 8;
+            _data.WritePubKey(playerOne, offset);
+            offset += 32;
+            _data.WritePubKey(playerTwo, offset);
+            offset += 32;
+            _data.WritePubKey(winner, offset);
+            return _data;
+        }
+    }
 }
\ No newline at end of file
@@ -1,10 +1,67 @@
 use anchor_lang::prelude::*;
 
 #[constant]
-pub const ADMIN_SEED: &'static [u8] = b"admin";
+pub const ADMIN_SEED: &[u8] = b"admin";
 
 #[constant]
-pub const BATTLE_SEED: &'static [u8] = b"battle";
+pub const BATTLE_SEED: &[u8] = b"battle";
 
 #[constant]
-pub const TOKEN_ACCOUNT_SEED: &'static [u8] = b"token_account";
+pub const TOKEN_ACCOUNT_SEED: &[u8] = b"token_account";
+
+#[constant]
+pub const MINT_CONFIG_SEED: &[u8] = b"mint_config";
+
+#[constant]
+pub const PENDING_MINT_CONFIG_SEED: &[u8] = b"pending_mint_config";
+
+#[constant]
+pub const SOL_VAULT_SEED: &[u8] = b"sol_vault";
+
+/// Stands in for the mint of SOL battles, in their mint config and `Battle::mint`
+pub const SOL_MINT: Pubkey = Pubkey::new_from_array([0; 32]);
+
+#[constant]
+pub const SOL_DECIMALS: u8 = 9;
+
+/// Pauses `create_battle` and `create_sol_battle`
+#[constant]
+pub const PAUSE_CREATE: u8 = 1 << 0;
+
+/// Pauses `join_battle` and `join_sol_battle`
+#[constant]
+pub const PAUSE_JOIN: u8 = 1 << 1;
+
+#[constant]
+pub const PAUSE_ALL: u8 = PAUSE_CREATE | PAUSE_JOIN;
+
+/// The longest dispute window, results can't be held back from payout for longer
+#[constant]
+pub const MAX_DISPUTE_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// The longest config delay, so a delay set by mistake can still be waited out
+#[constant]
+pub const MAX_CONFIG_DELAY_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+#[constant]
+pub const ADMIN_VERSION: u8 = 1;
+
+/// 2 records each player's deposit, older battles read their deposits as zero.
+/// 3 grew the account for `invited_opponent`, older battles need `migrate_battle`.
+#[constant]
+pub const BATTLE_VERSION: u8 = 3;
+
+#[constant]
+pub const PLAYER_PROFILE_SEED: &[u8] = b"player_profile";
+
+/// 2 retired the wagered and won totals, `migrate_player_profile` zeroes them.
+#[constant]
+pub const PLAYER_PROFILE_VERSION: u8 = 2;
+
+/// The Elo rating a player starts on
+#[constant]
+pub const DEFAULT_RATING: u32 = 1200;
+
+/// The most rating points a single battle can move
+#[constant]
+pub const RATING_K_FACTOR: u32 = 32;
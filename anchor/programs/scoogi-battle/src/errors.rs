@@ -25,4 +25,108 @@ pub enum ScoogiBattleError {
     /// 0x1775 - 6005
     #[msg("Unauthorized")]
     Unauthorized,
+
+    /// 0x1776 - 6006
+    #[msg("Winner does not match the battle result")]
+    InvalidWinner,
+
+    /// 0x1777 - 6007
+    #[msg("Invalid result signature")]
+    InvalidResultSignature,
+
+    /// 0x1778 - 6008
+    #[msg("Invalid settlement mode")]
+    InvalidSettlementMode,
+
+    /// 0x1779 - 6009
+    #[msg("Result already attested")]
+    AlreadyAttested,
+
+    /// 0x177A - 6010
+    #[msg("Both players must attest the same result")]
+    MissingAttestation,
+
+    /// 0x177B - 6011
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+
+    /// 0x177C - 6012
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+
+    /// 0x177D - 6013
+    #[msg("Battle has not expired")]
+    BattleNotExpired,
+
+    /// 0x177E - 6014
+    #[msg("Fee is out of range")]
+    FeeOutOfRange,
+
+    /// 0x177F - 6015
+    #[msg("Invalid referrer")]
+    InvalidReferrer,
+
+    /// 0x1780 - 6016
+    #[msg("Referrer token account is missing")]
+    MissingReferrerAccount,
+
+    /// 0x1781 - 6017
+    #[msg("Price must be greater than zero")]
+    PriceZero,
+
+    /// 0x1782 - 6018
+    #[msg("Price overflows the mint's base units")]
+    PriceOverflow,
+
+    /// 0x1783 - 6019
+    #[msg("Stake is out of range")]
+    StakeOutOfRange,
+
+    /// 0x1784 - 6020
+    #[msg("Mint is not accepted for wagers")]
+    MintNotAccepted,
+
+    /// 0x1785 - 6021
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    /// 0x1786 - 6022
+    #[msg("No config change is queued")]
+    NoPendingConfig,
+
+    /// 0x1787 - 6023
+    #[msg("Config change is still timelocked")]
+    ConfigTimelocked,
+
+    /// 0x1788 - 6024
+    #[msg("Rating band minimum is above its maximum")]
+    InvalidRatingBand,
+
+    /// 0x1789 - 6025
+    #[msg("Challenger rating is outside the battle's rating band")]
+    RatingOutOfBand,
+
+    /// 0x178A - 6026
+    #[msg("Player can't be invited to their own battle")]
+    InvalidOpponent,
+
+    /// 0x178B - 6027
+    #[msg("Battle is reserved for its invited opponent")]
+    NotInvited,
+
+    /// 0x178C - 6028
+    #[msg("Mint is already the admin's mint")]
+    MintUnchanged,
+
+    /// 0x178D - 6029
+    #[msg("Config delay is above the maximum")]
+    InvalidConfigDelay,
+
+    /// 0x178E - 6030
+    #[msg("Dispute window is above the maximum")]
+    InvalidDisputeWindow,
+
+    /// 0x178F - 6031
+    #[msg("Treasury is not set")]
+    MissingTreasury,
 }
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use crate::{Battle, Payout};
+
+// Battles are identified by their seeds, `battle_id` and `player_one`, so
+// indexers can follow a battle after its account is closed
+
+/// A battle opened by its host, waiting on a challenger
+#[event]
+pub struct BattleCreated {
+    pub battle_id: u64,
+    pub player_one: Pubkey,
+    pub mint: Pubkey,
+    pub stake_amount: u64,
+    /// `Pubkey::default()` when anyone can join
+    pub invited_opponent: Pubkey,
+}
+
+impl BattleCreated {
+    pub fn new(battle: &Battle) -> Self {
+        Self {
+            battle_id: battle.battle_id,
+            player_one: battle.player_one,
+            mint: battle.mint,
+            stake_amount: battle.stake_amount,
+            invited_opponent: battle.invited_opponent,
+        }
+    }
+}
+
+#[event]
+pub struct BattleJoined {
+    pub battle_id: u64,
+    pub player_one: Pubkey,
+    pub player_two: Pubkey,
+}
+
+impl BattleJoined {
+    pub fn new(battle: &Battle) -> Self {
+        Self {
+            battle_id: battle.battle_id,
+            player_one: battle.player_one,
+            player_two: battle.player_two,
+        }
+    }
+}
+
+/// A battle paid out to its winner, amounts are in base units of its mint and
+/// `referrer_amount` went to each referrer
+#[event]
+pub struct BattleSettled {
+    pub battle_id: u64,
+    pub player_one: Pubkey,
+    pub winner: Pubkey,
+    pub winner_amount: u64,
+    pub burn_amount: u64,
+    pub treasury_amount: u64,
+    pub referrer_amount: u64,
+}
+
+impl BattleSettled {
+    pub fn new(battle: &Battle, payout: &Payout) -> Self {
+        Self {
+            battle_id: battle.battle_id,
+            player_one: battle.player_one,
+            winner: battle.winner,
+            winner_amount: payout.winner_amount,
+            burn_amount: payout.burn_amount,
+            treasury_amount: payout.treasury_amount,
+            referrer_amount: payout.referrer_amount,
+        }
+    }
+}
+
+/// An oracle result waiting out the dispute window before it is paid out
+#[event]
+pub struct BattleResultRecorded {
+    pub battle_id: u64,
+    pub player_one: Pubkey,
+    pub winner: Pubkey,
+    pub dispute_deadline: i64,
+}
+
+impl BattleResultRecorded {
+    pub fn new(battle: &Battle) -> Self {
+        Self {
+            battle_id: battle.battle_id,
+            player_one: battle.player_one,
+            winner: battle.winner,
+            dispute_deadline: battle.dispute_deadline,
+        }
+    }
+}
+
+/// A battle locked until the admin resolves it, by conflicting attestations or
+/// the loser disputing a recorded result
+#[event]
+pub struct BattleDisputed {
+    pub battle_id: u64,
+    pub player_one: Pubkey,
+    /// the player whose attestation or dispute locked the battle
+    pub disputed_by: Pubkey,
+}
+
+impl BattleDisputed {
+    pub fn new(battle: &Battle, disputed_by: Pubkey) -> Self {
+        Self {
+            battle_id: battle.battle_id,
+            player_one: battle.player_one,
+            disputed_by,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum CancelReason {
+    /// player one withdrew before anyone joined
+    Withdrawn,
+    /// the invited opponent declined
+    Declined,
+    /// the battle timed out
+    Expired,
+    /// the admin refunded a disputed battle
+    Refunded,
+    /// the treasury authority refunded the players
+    AdminWithdrawn,
+}
+
+/// A battle closed without a winner, its stakes refunded
+#[event]
+pub struct BattleCancelled {
+    pub battle_id: u64,
+    pub player_one: Pubkey,
+    pub reason: CancelReason,
+}
+
+impl BattleCancelled {
+    pub fn new(battle: &Battle, reason: CancelReason) -> Self {
+        Self {
+            battle_id: battle.battle_id,
+            player_one: battle.player_one,
+            reason,
+        }
+    }
+}
+
+/// The admin account or a mint config changed, or a mint config change was queued,
+/// fetch `config` for its new state
+#[event]
+pub struct ConfigUpdated {
+    pub config: Pubkey,
+    /// the role that signed, `Pubkey::default()` for `apply_config` which anyone can call
+    pub authority: Pubkey,
+}
+
+/// Both players' Elo ratings after a battle was paid out
+#[event]
+pub struct RatingsUpdated {
+    pub battle_id: u64,
+    pub winner: Pubkey,
+    pub winner_rating: u32,
+    pub loser: Pubkey,
+    pub loser_rating: u32,
+    pub rating_change: u32,
+}
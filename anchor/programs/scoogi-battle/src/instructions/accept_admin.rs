@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, signer)]
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = pending_admin @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+}
+
+/// Completes the handover, the proposed key has to sign so it cannot go to a
+/// key nobody holds. Roles the outgoing admin held move with it.
+pub fn accept_admin_ix(ctx: Context<AcceptAdmin>) -> Result<()> {
+    ctx.accounts
+        .admin_account
+        .hand_over(ctx.accounts.pending_admin.key());
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.pending_admin.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    constants, Admin, ConfigUpdated, FeeSchedule, MintConfig, PendingMintConfig, ScoogiBattleError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddMintConfig<'info> {
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
+
+    #[account(
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        init_if_needed,
+        payer = config_manager,
+        space = PendingMintConfig::size(),
+        seeds = [constants::PENDING_MINT_CONFIG_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub pending_mint_config: Account<'info, PendingMintConfig>,
+
+    #[account(token::mint = mint)]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Whitelists a mint for wagers or changes its terms, prices and bounds are in whole
+/// tokens like `update_battle_price` and `update_stake_bounds`. The terms wait out
+/// the config delay and land with `apply_mint_config`.
+pub fn add_mint_config_ix(
+    ctx: Context<AddMintConfig>,
+    battle_price: u64,
+    min_stake: u64,
+    max_stake: u64,
+    fee_schedule: FeeSchedule,
+) -> Result<()> {
+    let mut terms = MintConfig {
+        mint: ctx.accounts.mint.key(),
+        battle_price: 0,
+        min_stake: 0,
+        max_stake: 0,
+        fee_schedule: FeeSchedule::default(),
+        treasury_token_account: ctx.accounts.treasury_token_account.key(),
+    };
+    terms.set_terms(
+        ctx.accounts.mint.decimals,
+        battle_price,
+        min_stake,
+        max_stake,
+        fee_schedule,
+    )?;
+
+    let effective_at = ctx.accounts.admin_account.config_effective_at()?;
+    ctx.accounts.pending_mint_config.queue(&terms, effective_at);
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.pending_mint_config.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants, Admin, ConfigUpdated, FeeSchedule, MintConfig, PendingMintConfig, ScoogiBattleError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddSolConfig<'info> {
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
+
+    #[account(
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        init_if_needed,
+        payer = config_manager,
+        space = PendingMintConfig::size(),
+        seeds = [constants::PENDING_MINT_CONFIG_SEED, constants::SOL_MINT.as_ref()],
+        bump
+    )]
+    pub pending_mint_config: Account<'info, PendingMintConfig>,
+
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accepts SOL for wagers or changes its terms, prices and bounds are in whole SOL.
+/// Like `add_mint_config` the terms land with `apply_mint_config`, and SOL is removed
+/// again with `remove_mint_config`.
+pub fn add_sol_config_ix(
+    ctx: Context<AddSolConfig>,
+    battle_price: u64,
+    min_stake: u64,
+    max_stake: u64,
+    fee_schedule: FeeSchedule,
+) -> Result<()> {
+    let mut terms = MintConfig {
+        mint: constants::SOL_MINT,
+        battle_price: 0,
+        min_stake: 0,
+        max_stake: 0,
+        fee_schedule: FeeSchedule::default(),
+        treasury_token_account: ctx.accounts.treasury.key(),
+    };
+    terms.set_terms(
+        constants::SOL_DECIMALS,
+        battle_price,
+        min_stake,
+        max_stake,
+        fee_schedule,
+    )?;
+
+    let effective_at = ctx.accounts.admin_account.config_effective_at()?;
+    ctx.accounts.pending_mint_config.queue(&terms, effective_at);
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.pending_mint_config.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
+
+    Ok(())
+}
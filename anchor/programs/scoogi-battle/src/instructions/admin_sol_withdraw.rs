@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants, utils, Admin, Battle, BattleCancelled, CancelReason, ScoogiBattleError, SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct AdminSolWithdraw<'info> {
+    #[account(mut, signer)]
+    pub treasury_authority: Signer<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    #[account(mut)]
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: checked against the battle account, only needed once player two has joined
+    #[account(mut, address = battle_account.player_two)]
+    pub player_two: Option<AccountInfo<'info>>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = admin,
+        has_one = treasury_authority @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// CHECK: the treasury wallet snapshotted onto the battle
+    #[account(mut, address = battle_account.treasury_token_account)]
+    pub treasury: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Refunds each player exactly what they deposited, anything else above the
+/// vault's rent is swept to the treasury
+pub fn admin_sol_withdraw_ix(ctx: Context<AdminSolWithdraw>, _battle_id: u64) -> Result<()> {
+    if ctx.accounts.treasury_authority.key() != ctx.accounts.admin_account.treasury_authority {
+        return Err(ScoogiBattleError::InvalidWithdrawal.into());
+    }
+
+    let escrow = utils::SolEscrow {
+        sol_vault: ctx.accounts.sol_vault.to_account_info(),
+    };
+
+    let (mut player_one_refund, player_two_refund, mut swept) = ctx
+        .accounts
+        .battle_account
+        .withdrawal_refunds(escrow.pot()?)?;
+
+    // an empty treasury wallet cannot be funded with less than its rent exemption
+    if !utils::SolEscrow::can_receive(&ctx.accounts.treasury, swept)? {
+        player_one_refund = player_one_refund.checked_add(swept).unwrap();
+        swept = 0;
+    }
+
+    escrow.transfer(&ctx.accounts.player_one, player_one_refund)?;
+
+    // a pending battle has no player two to refund
+    if player_two_refund > 0 {
+        let player_two = ctx
+            .accounts
+            .player_two
+            .as_ref()
+            .ok_or(ScoogiBattleError::InvalidWithdrawal)?;
+
+        escrow.transfer(player_two, player_two_refund)?;
+    }
+
+    // stray lamports sent to the vault
+    if swept > 0 {
+        escrow.transfer(&ctx.accounts.treasury, swept)?;
+    }
+
+    emit_cpi!(BattleCancelled::new(
+        &ctx.accounts.battle_account,
+        CancelReason::AdminWithdrawn
+    ));
+
+    Ok(())
+}
@@ -1,17 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_2022 as token,
-    token_interface::{CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{constants, Admin, Battle, ScoogiBattleError};
+use crate::{constants, utils, Admin, Battle, BattleCancelled, CancelReason, ScoogiBattleError};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(battle_id: u64)]
 pub struct AdminWithdraw<'info> {
     #[account(mut, signer)]
-    admin: Signer<'info>,
+    pub treasury_authority: Signer<'info>,
 
     /// CHECK: passed in here for use in the seeds
     pub player_one: AccountInfo<'info>,
@@ -19,13 +19,26 @@ pub struct AdminWithdraw<'info> {
     /// CHECK: passed in here for use in the seeds
     pub player_two: AccountInfo<'info>,
 
-    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = mint, has_one = admin)]
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = admin,
+        has_one = treasury_authority @ ScoogiBattleError::Unauthorized
+    )]
     pub admin_account: Account<'info, Admin>,
 
     #[account(
         mut,
         close = admin,
-        seeds = [constants::BATTLE_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         has_one = player_one,
         has_one = player_two
@@ -34,7 +47,11 @@ pub struct AdminWithdraw<'info> {
 
     #[account(
         mut,
-        seeds = [constants::TOKEN_ACCOUNT_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::TOKEN_ACCOUNT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         token::mint = mint,
         token::authority = battle_token_account,
@@ -44,23 +61,26 @@ pub struct AdminWithdraw<'info> {
 
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = treasury_authority,
         associated_token::mint = mint,
         associated_token::authority = player_one,
         associated_token::token_program = token_program
     )]
     pub player_one_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    // only needed once player two has joined
     #[account(
-        init_if_needed,
-        payer = admin,
-        associated_token::mint = mint,
-        associated_token::authority = player_two,
-        associated_token::token_program = token_program
+        mut,
+        token::mint = mint,
+        token::authority = player_two,
+        token::token_program = token_program
     )]
-    pub player_two_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub player_two_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, address = battle_account.treasury_token_account)]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(constraint = mint.key() == admin_account.mint)]
+    #[account(constraint = mint.key() == battle_account.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
 
     pub system_program: Program<'info, System>,
@@ -68,64 +88,67 @@ pub struct AdminWithdraw<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+/// Refunds each player exactly what they deposited, anything else left in the
+/// battle token account is swept to the treasury so it can be closed
 pub fn admin_withdraw_ix(ctx: Context<AdminWithdraw>, battle_id: u64) -> Result<()> {
-    if ctx.accounts.battle_account.battle_id != battle_id {
-        return Err(ScoogiBattleError::InvalidBattleId.into());
-    }
-
-    if ctx.accounts.admin.key() != ctx.accounts.admin_account.admin {
+    if ctx.accounts.treasury_authority.key() != ctx.accounts.admin_account.treasury_authority {
         return Err(ScoogiBattleError::InvalidWithdrawal.into());
     }
 
     let player_one_key = ctx.accounts.player_one.key();
-    let amount = ctx
-        .accounts
-        .battle_token_account
-        .amount
-        .checked_div(2)
-        .unwrap();
-
+    let battle_id_bytes = battle_id.to_le_bytes();
     let bump = ctx.bumps.battle_token_account;
     let signer_seeds: &[&[&[u8]]] = &[&[
         constants::TOKEN_ACCOUNT_SEED,
         player_one_key.as_ref(),
+        battle_id_bytes.as_ref(),
         &[bump],
     ]];
 
-    // transfer player one's tokens back to them
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_accounts = TransferChecked {
-        from: ctx.accounts.battle_token_account.to_account_info(),
+    let escrow = utils::BattleEscrow {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        battle_token_account: ctx.accounts.battle_token_account.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.player_one_token_account.to_account_info(),
-        authority: ctx.accounts.battle_token_account.to_account_info(),
+        decimals: ctx.accounts.mint.decimals,
+        signer_seeds,
     };
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-
-    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
 
-    // transfer player two's tokens back to them
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_accounts = TransferChecked {
-        from: ctx.accounts.battle_token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.player_two_token_account.to_account_info(),
-        authority: ctx.accounts.battle_token_account.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    let (player_one_refund, player_two_refund, swept) = ctx
+        .accounts
+        .battle_account
+        .withdrawal_refunds(ctx.accounts.battle_token_account.amount)?;
+
+    escrow.transfer(
+        &ctx.accounts.player_one_token_account.to_account_info(),
+        player_one_refund,
+    )?;
+
+    // a pending battle has no player two to refund
+    if player_two_refund > 0 {
+        let player_two_token_account = ctx
+            .accounts
+            .player_two_token_account
+            .as_ref()
+            .ok_or(ScoogiBattleError::InvalidWithdrawal)?
+            .to_account_info();
+
+        escrow.transfer(&player_two_token_account, player_two_refund)?;
+    }
 
-    token::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    // stray tokens sent to the battle token account, or rounding dust from a short pot
+    if swept > 0 {
+        escrow.transfer(
+            &ctx.accounts.treasury_token_account.to_account_info(),
+            swept,
+        )?;
+    }
 
-    // Close the battle token account
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_accounts = CloseAccount {
-        account: ctx.accounts.battle_token_account.to_account_info(),
-        destination: ctx.accounts.admin.to_account_info(),
-        authority: ctx.accounts.battle_token_account.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    escrow.close(&ctx.accounts.admin.to_account_info())?;
 
-    token::close_account(cpi_ctx)?;
+    emit_cpi!(BattleCancelled::new(
+        &ctx.accounts.battle_account,
+        CancelReason::AdminWithdrawn
+    ));
 
     Ok(())
 }
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApplyConfig<'info> {
+    #[account(mut, seeds = [constants::ADMIN_SEED], bump)]
+    pub admin_account: Account<'info, Admin>,
+}
+
+/// Applies a queued price, fee or config delay change once the config delay has passed,
+/// anyone can call it
+pub fn apply_config_ix(ctx: Context<ApplyConfig>) -> Result<()> {
+    ctx.accounts.admin_account.apply_config()?;
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: Pubkey::default(),
+    });
+
+    Ok(())
+}
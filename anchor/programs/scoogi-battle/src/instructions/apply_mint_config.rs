@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated, MintConfig, PendingMintConfig, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApplyMintConfig<'info> {
+    /// pays for a mint config created here
+    #[account(mut, signer)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = config_manager)]
+    pub admin_account: Account<'info, Admin>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub config_manager: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = config_manager,
+        seeds = [constants::PENDING_MINT_CONFIG_SEED, pending_mint_config.mint.as_ref()],
+        bump
+    )]
+    pub pending_mint_config: Account<'info, PendingMintConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MintConfig::size(),
+        seeds = [constants::MINT_CONFIG_SEED, pending_mint_config.mint.as_ref()],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Applies a queued mint config change once the config delay has passed,
+/// anyone can call it
+pub fn apply_mint_config_ix(ctx: Context<ApplyMintConfig>) -> Result<()> {
+    if Clock::get()?.unix_timestamp < ctx.accounts.pending_mint_config.effective_at {
+        return Err(ScoogiBattleError::ConfigTimelocked.into());
+    }
+
+    if ctx.accounts.pending_mint_config.remove {
+        ctx.accounts
+            .mint_config
+            .close(ctx.accounts.config_manager.to_account_info())?;
+    } else {
+        ctx.accounts
+            .pending_mint_config
+            .apply(&mut ctx.accounts.mint_config);
+    }
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.mint_config.key(),
+        authority: Pubkey::default(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Battle, BattleDisputed, BattleStatus, ScoogiBattleError, SettlementMode};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct AttestBattleResult<'info> {
+    #[account(signer)]
+    pub player: Signer<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_one: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+    )]
+    pub battle_account: Account<'info, Battle>,
+}
+
+pub fn attest_battle_result_ix(
+    ctx: Context<AttestBattleResult>,
+    _battle_id: u64,
+    battle_result: u8,
+) -> Result<()> {
+    let battle = &mut ctx.accounts.battle_account;
+
+    if battle.settlement_mode != SettlementMode::DualConfirmation {
+        return Err(ScoogiBattleError::InvalidSettlementMode.into());
+    }
+
+    match battle.battle_status {
+        BattleStatus::InProgress => {
+            // validates the result
+            battle.winner_for(battle_result)?;
+
+            let player = ctx.accounts.player.key();
+            let attestation = if player == battle.player_one {
+                &mut battle.player_one_attestation
+            } else if player == battle.player_two {
+                &mut battle.player_two_attestation
+            } else {
+                return Err(ScoogiBattleError::Unauthorized.into());
+            };
+
+            if attestation.is_some() {
+                return Err(ScoogiBattleError::AlreadyAttested.into());
+            }
+
+            *attestation = Some(battle_result);
+
+            // conflicting claims lock the pot until the admin steps in
+            if let (Some(one), Some(two)) =
+                (battle.player_one_attestation, battle.player_two_attestation)
+            {
+                if one != two {
+                    battle.battle_status = BattleStatus::Disputed;
+
+                    emit_cpi!(BattleDisputed::new(battle, player));
+                }
+            }
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+}
+
+pub fn cancel_admin_transfer_ix(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+    ctx.accounts.admin_account.pending_admin = Pubkey::default();
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.admin.key(),
+    });
+
+    Ok(())
+}
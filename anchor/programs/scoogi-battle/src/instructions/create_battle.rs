@@ -5,22 +5,36 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::{constants, Admin, Battle, BattleStatus};
+use crate::{
+    constants, Admin, Battle, BattleCreated, MintConfig, PlayerProfile, RatingBand,
+    ScoogiBattleError, SettlementMode,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(battle_id: u64)]
 pub struct CreateBattle<'info> {
     #[account(mut, signer)]
     pub player_one: Signer<'info>,
 
-    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = mint)]
+    #[account(seeds = [constants::ADMIN_SEED], bump)]
     pub admin_account: Account<'info, Admin>,
 
+    /// required for any mint other than the admin's default mint
+    #[account(seeds = [constants::MINT_CONFIG_SEED, mint.key().as_ref()], bump)]
+    pub mint_config: Option<Account<'info, MintConfig>>,
+
+    // keyed by battle_id so a player can host several battles at once,
+    // `init` rejects a battle_id that is already in use
     #[account(
         init,
         payer = player_one,
         space = Battle::size(),
-        seeds = [constants::BATTLE_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump
     )]
     pub battle_account: Account<'info, Battle>,
@@ -35,7 +49,11 @@ pub struct CreateBattle<'info> {
 
     #[account(
         init,
-        seeds = [constants::TOKEN_ACCOUNT_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::TOKEN_ACCOUNT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         payer = player_one,
         token::mint = mint,
@@ -44,19 +62,61 @@ pub struct CreateBattle<'info> {
     )]
     pub battle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(constraint = mint.key() == admin_account.mint)]
+    #[account(
+        constraint = mint.key() == admin_account.mint || mint_config.is_some()
+            @ ScoogiBattleError::MintNotAccepted
+    )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
+    #[account(
+        init_if_needed,
+        payer = player_one,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, player_one.key().as_ref()],
+        bump
+    )]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn create_battle_ix(ctx: Context<CreateBattle>, battle_id: u64) -> Result<()> {
-    ctx.accounts.battle_account.battle_id = battle_id;
-    ctx.accounts.battle_account.player_one = ctx.accounts.player_one.key();
-    ctx.accounts.battle_account.player_two = Pubkey::default();
-    ctx.accounts.battle_account.battle_status = BattleStatus::Pending;
+pub fn create_battle_ix(
+    ctx: Context<CreateBattle>,
+    battle_id: u64,
+    settlement_mode: SettlementMode,
+    referrer: Option<Pubkey>,
+    stake: Option<u64>,
+    rating_band: Option<RatingBand>,
+    invited_opponent: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts
+        .admin_account
+        .require_unpaused(constants::PAUSE_CREATE)?;
+
+    let mint_config = match &ctx.accounts.mint_config {
+        Some(mint_config) => MintConfig::clone(mint_config),
+        None => ctx.accounts.admin_account.mint_config(),
+    };
+
+    // stakes are in base units so wagers below one whole token are possible
+    let stake_amount = mint_config.stake_amount(stake)?;
+
+    ctx.accounts
+        .player_one_profile
+        .open(ctx.accounts.player_one.key());
+
+    ctx.accounts.battle_account.open(
+        battle_id,
+        ctx.accounts.player_one.key(),
+        settlement_mode,
+        &mint_config,
+        stake_amount,
+        referrer,
+    )?;
+    ctx.accounts.battle_account.set_rating_band(rating_band)?;
+    ctx.accounts.battle_account.invite(invited_opponent)?;
 
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_accounts = TransferChecked {
@@ -69,9 +129,11 @@ pub fn create_battle_ix(ctx: Context<CreateBattle>, battle_id: u64) -> Result<()
 
     token::transfer_checked(
         cpi_ctx,
-        ctx.accounts.admin_account.battle_price,
+        ctx.accounts.battle_account.stake_amount,
         ctx.accounts.mint.decimals,
     )?;
 
+    emit_cpi!(BattleCreated::new(&ctx.accounts.battle_account));
+
     Ok(())
 }
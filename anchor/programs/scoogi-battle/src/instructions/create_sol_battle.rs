@@ -0,0 +1,103 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    constants, Admin, Battle, BattleCreated, MintConfig, PlayerProfile, RatingBand, SettlementMode,
+    SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct CreateSolBattle<'info> {
+    #[account(mut, signer)]
+    pub player_one: Signer<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump)]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(seeds = [constants::MINT_CONFIG_SEED, constants::SOL_MINT.as_ref()], bump)]
+    pub mint_config: Account<'info, MintConfig>,
+
+    #[account(
+        init,
+        payer = player_one,
+        space = Battle::size(),
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        init,
+        payer = player_one,
+        space = SolVault::size(),
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    #[account(
+        init_if_needed,
+        payer = player_one,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, player_one.key().as_ref()],
+        bump
+    )]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a battle staked in SOL, needs no token accounts
+pub fn create_sol_battle_ix(
+    ctx: Context<CreateSolBattle>,
+    battle_id: u64,
+    settlement_mode: SettlementMode,
+    referrer: Option<Pubkey>,
+    stake: Option<u64>,
+    rating_band: Option<RatingBand>,
+    invited_opponent: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts
+        .admin_account
+        .require_unpaused(constants::PAUSE_CREATE)?;
+
+    // stakes are in lamports so wagers below one SOL are possible
+    let stake_amount = ctx.accounts.mint_config.stake_amount(stake)?;
+
+    ctx.accounts
+        .player_one_profile
+        .open(ctx.accounts.player_one.key());
+
+    ctx.accounts.battle_account.open(
+        battle_id,
+        ctx.accounts.player_one.key(),
+        settlement_mode,
+        &ctx.accounts.mint_config,
+        stake_amount,
+        referrer,
+    )?;
+    ctx.accounts.battle_account.set_rating_band(rating_band)?;
+    ctx.accounts.battle_account.invite(invited_opponent)?;
+
+    let cpi_program = ctx.accounts.system_program.to_account_info();
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.player_one.to_account_info(),
+        to: ctx.accounts.sol_vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+    system_program::transfer(cpi_ctx, ctx.accounts.battle_account.stake_amount)?;
+
+    emit_cpi!(BattleCreated::new(&ctx.accounts.battle_account));
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants, utils, Admin, Battle, BattleCancelled, BattleStatus, CancelReason, ScoogiBattleError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct DeclineBattle<'info> {
+    #[account(mut, signer)]
+    pub invited_opponent: Signer<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+        has_one = invited_opponent @ ScoogiBattleError::NotInvited,
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::TOKEN_ACCOUNT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = battle_token_account,
+        token::token_program = token_program
+    )]
+    pub battle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player_one,
+        associated_token::token_program = token_program
+    )]
+    pub player_one_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(constraint = mint.key() == battle_account.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Lets the invited opponent turn down a challenge, refunding its host
+pub fn decline_battle_ix(ctx: Context<DeclineBattle>, battle_id: u64) -> Result<()> {
+    match ctx.accounts.battle_account.battle_status {
+        BattleStatus::Pending => {
+            let player_one_key = ctx.accounts.player_one.key();
+            let battle_id_bytes = battle_id.to_le_bytes();
+            let bump = ctx.bumps.battle_token_account;
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                constants::TOKEN_ACCOUNT_SEED,
+                player_one_key.as_ref(),
+                battle_id_bytes.as_ref(),
+                &[bump],
+            ]];
+
+            let escrow = utils::BattleEscrow {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                battle_token_account: ctx.accounts.battle_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                decimals: ctx.accounts.mint.decimals,
+                signer_seeds,
+            };
+
+            escrow.transfer(
+                &ctx.accounts.player_one_token_account.to_account_info(),
+                ctx.accounts.battle_token_account.amount,
+            )?;
+            escrow.close(&ctx.accounts.admin.to_account_info())?;
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    emit_cpi!(BattleCancelled::new(
+        &ctx.accounts.battle_account,
+        CancelReason::Declined
+    ));
+
+    Ok(())
+}
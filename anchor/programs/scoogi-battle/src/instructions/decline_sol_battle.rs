@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants, utils, Admin, Battle, BattleCancelled, BattleStatus, CancelReason,
+    ScoogiBattleError, SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct DeclineSolBattle<'info> {
+    #[account(mut, signer)]
+    pub invited_opponent: Signer<'info>,
+
+    /// CHECK: checked against the battle account, receives the refund
+    #[account(mut)]
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+        has_one = invited_opponent @ ScoogiBattleError::NotInvited,
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets the invited opponent turn down a SOL challenge, refunding its host
+pub fn decline_sol_battle_ix(ctx: Context<DeclineSolBattle>, _battle_id: u64) -> Result<()> {
+    match ctx.accounts.battle_account.battle_status {
+        BattleStatus::Pending => {
+            let escrow = utils::SolEscrow {
+                sol_vault: ctx.accounts.sol_vault.to_account_info(),
+            };
+
+            escrow.transfer(&ctx.accounts.player_one, escrow.pot()?)?;
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    emit_cpi!(BattleCancelled::new(
+        &ctx.accounts.battle_account,
+        CancelReason::Declined
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Battle, BattleDisputed, BattleStatus, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct DisputeBattleResult<'info> {
+    #[account(signer)]
+    pub player: Signer<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_one: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+    )]
+    pub battle_account: Account<'info, Battle>,
+}
+
+pub fn dispute_battle_result_ix(ctx: Context<DisputeBattleResult>, _battle_id: u64) -> Result<()> {
+    let battle = &mut ctx.accounts.battle_account;
+
+    match battle.battle_status {
+        BattleStatus::ResultRecorded => {
+            // only the losing player can dispute the result
+            let player = ctx.accounts.player.key();
+            if (player != battle.player_one && player != battle.player_two)
+                || player == battle.winner
+            {
+                return Err(ScoogiBattleError::Unauthorized.into());
+            }
+
+            if Clock::get()?.unix_timestamp >= battle.dispute_deadline {
+                return Err(ScoogiBattleError::DisputeWindowClosed.into());
+            }
+
+            battle.battle_status = BattleStatus::Disputed;
+
+            emit_cpi!(BattleDisputed::new(battle, player));
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    Ok(())
+}
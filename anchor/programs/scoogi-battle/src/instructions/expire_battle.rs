@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants, utils, Admin, Battle, BattleCancelled, BattleStatus, CancelReason, ScoogiBattleError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct ExpireBattle<'info> {
+    #[account(mut, signer)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: checked against the battle account
+    pub player_two: AccountInfo<'info>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+        has_one = player_two
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::TOKEN_ACCOUNT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = battle_token_account,
+        token::token_program = token_program
+    )]
+    pub battle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = player_one,
+        associated_token::token_program = token_program
+    )]
+    pub player_one_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // only needed once player two has joined
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = player_two,
+        token::token_program = token_program
+    )]
+    pub player_two_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(constraint = mint.key() == battle_account.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Refunds a battle nobody finished once the admin's battle timeout has passed,
+/// anyone can call it
+pub fn expire_battle_ix(ctx: Context<ExpireBattle>, battle_id: u64) -> Result<()> {
+    let expires_at = ctx
+        .accounts
+        .battle_account
+        .expires_at(ctx.accounts.admin_account.battle_timeout_seconds)
+        .ok_or(ScoogiBattleError::BattleNotExpired)?;
+
+    if Clock::get()?.unix_timestamp < expires_at {
+        return Err(ScoogiBattleError::BattleNotExpired.into());
+    }
+
+    let player_one_key = ctx.accounts.player_one.key();
+    let battle_id_bytes = battle_id.to_le_bytes();
+    let bump = ctx.bumps.battle_token_account;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        constants::TOKEN_ACCOUNT_SEED,
+        player_one_key.as_ref(),
+        battle_id_bytes.as_ref(),
+        &[bump],
+    ]];
+
+    let escrow = utils::BattleEscrow {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        battle_token_account: ctx.accounts.battle_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        decimals: ctx.accounts.mint.decimals,
+        signer_seeds,
+    };
+
+    let pot = ctx.accounts.battle_token_account.amount;
+    let player_one_token_account = ctx.accounts.player_one_token_account.to_account_info();
+
+    match ctx.accounts.battle_account.battle_status {
+        // only player one has staked
+        BattleStatus::Pending => {
+            escrow.transfer(&player_one_token_account, pot)?;
+        }
+        BattleStatus::InProgress => {
+            let player_two_token_account = ctx
+                .accounts
+                .player_two_token_account
+                .as_ref()
+                .ok_or(ScoogiBattleError::InvalidWithdrawal)?
+                .to_account_info();
+
+            escrow.refund(pot, &player_one_token_account, &player_two_token_account)?;
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    escrow.close(&ctx.accounts.admin.to_account_info())?;
+
+    emit_cpi!(BattleCancelled::new(
+        &ctx.accounts.battle_account,
+        CancelReason::Expired
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants, utils, Admin, Battle, BattleCancelled, BattleStatus, CancelReason,
+    ScoogiBattleError, SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct ExpireSolBattle<'info> {
+    #[account(mut, signer)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: passed in here for use in the seeds, receives the refund
+    #[account(mut)]
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: checked against the battle account, only needed once player two has joined
+    #[account(mut, address = battle_account.player_two)]
+    pub player_two: Option<AccountInfo<'info>>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Refunds a SOL battle nobody finished once the admin's battle timeout has passed,
+/// anyone can call it
+pub fn expire_sol_battle_ix(ctx: Context<ExpireSolBattle>, _battle_id: u64) -> Result<()> {
+    let expires_at = ctx
+        .accounts
+        .battle_account
+        .expires_at(ctx.accounts.admin_account.battle_timeout_seconds)
+        .ok_or(ScoogiBattleError::BattleNotExpired)?;
+
+    if Clock::get()?.unix_timestamp < expires_at {
+        return Err(ScoogiBattleError::BattleNotExpired.into());
+    }
+
+    let escrow = utils::SolEscrow {
+        sol_vault: ctx.accounts.sol_vault.to_account_info(),
+    };
+
+    let pot = escrow.pot()?;
+
+    match ctx.accounts.battle_account.battle_status {
+        // only player one has staked
+        BattleStatus::Pending => {
+            escrow.transfer(&ctx.accounts.player_one, pot)?;
+        }
+        BattleStatus::InProgress => {
+            let player_two = ctx
+                .accounts
+                .player_two
+                .as_ref()
+                .ok_or(ScoogiBattleError::InvalidWithdrawal)?;
+
+            escrow.refund(pot, &ctx.accounts.player_one, player_two)?;
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    emit_cpi!(BattleCancelled::new(
+        &ctx.accounts.battle_account,
+        CancelReason::Expired
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants, utils, Admin, Battle, BattleSettled, BattleStatus, PlayerProfile, ScoogiBattleError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct FinalizeBattle<'info> {
+    #[account(mut, signer)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: checked against the battle's recorded winner
+    pub winner: AccountInfo<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(mut, address = battle_account.treasury_token_account)]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+        has_one = winner,
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::TOKEN_ACCOUNT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = battle_token_account,
+        token::token_program = token_program
+    )]
+    pub battle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = winner,
+        associated_token::token_program = token_program
+    )]
+    pub winner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = player_one_referrer_token_account.owner == battle_account.player_one_referrer
+            @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_one_referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = player_two_referrer_token_account.owner == battle_account.player_two_referrer
+            @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_two_referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, constraint = mint.key() == battle_account.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_one.as_ref()],
+        bump
+    )]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_two.as_ref()],
+        bump
+    )]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Pays out a recorded result once its dispute window has passed, anyone can call it
+pub fn finalize_battle_ix(ctx: Context<FinalizeBattle>, battle_id: u64) -> Result<()> {
+    match ctx.accounts.battle_account.battle_status {
+        BattleStatus::ResultRecorded => {
+            if Clock::get()?.unix_timestamp < ctx.accounts.battle_account.dispute_deadline {
+                return Err(ScoogiBattleError::DisputeWindowOpen.into());
+            }
+
+            ctx.accounts.battle_account.battle_status = BattleStatus::Completed;
+
+            let player_one_key = ctx.accounts.player_one.key();
+            let battle_id_bytes = battle_id.to_le_bytes();
+            let bump = ctx.bumps.battle_token_account;
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                constants::TOKEN_ACCOUNT_SEED,
+                player_one_key.as_ref(),
+                battle_id_bytes.as_ref(),
+                &[bump],
+            ]];
+
+            let referrer_token_accounts = utils::referrer_accounts(
+                &ctx.accounts.battle_account,
+                ctx.accounts
+                    .player_one_referrer_token_account
+                    .as_ref()
+                    .map(|account| account.to_account_info()),
+                ctx.accounts
+                    .player_two_referrer_token_account
+                    .as_ref()
+                    .map(|account| account.to_account_info()),
+            )?;
+
+            let escrow = utils::BattleEscrow {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                battle_token_account: ctx.accounts.battle_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                decimals: ctx.accounts.mint.decimals,
+                signer_seeds,
+            };
+
+            let payout = escrow.pay_out(
+                ctx.accounts.battle_token_account.amount,
+                &ctx.accounts.battle_account.fee_schedule,
+                &ctx.accounts.winner_token_account.to_account_info(),
+                &ctx.accounts.treasury_token_account.to_account_info(),
+                &referrer_token_accounts,
+            )?;
+            escrow.close(&ctx.accounts.admin.to_account_info())?;
+
+            emit_cpi!(BattleSettled::new(&ctx.accounts.battle_account, &payout));
+            emit_cpi!(utils::record_profiles(
+                &ctx.accounts.battle_account,
+                &mut ctx.accounts.player_one_profile,
+                &mut ctx.accounts.player_two_profile,
+            ));
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::{prelude::*, solana_program::incinerator};
+
+use crate::{
+    constants, utils, Admin, Battle, BattleSettled, BattleStatus, PlayerProfile, ScoogiBattleError,
+    SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct FinalizeSolBattle<'info> {
+    #[account(mut, signer)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: checked against the battle's recorded winner
+    #[account(mut)]
+    pub winner: AccountInfo<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    /// CHECK: the treasury wallet snapshotted onto the battle
+    #[account(mut, address = battle_account.treasury_token_account)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+        has_one = winner,
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// CHECK: checked against the battle's referrer
+    #[account(
+        mut,
+        address = battle_account.player_one_referrer @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_one_referrer: Option<AccountInfo<'info>>,
+
+    /// CHECK: checked against the battle's referrer
+    #[account(
+        mut,
+        address = battle_account.player_two_referrer @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_two_referrer: Option<AccountInfo<'info>>,
+
+    /// CHECK: receives the burned lamports
+    #[account(mut, address = incinerator::ID)]
+    pub incinerator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_one.as_ref()],
+        bump
+    )]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_two.as_ref()],
+        bump
+    )]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays out a recorded SOL result once its dispute window has passed, anyone can call it
+pub fn finalize_sol_battle_ix(ctx: Context<FinalizeSolBattle>, _battle_id: u64) -> Result<()> {
+    match ctx.accounts.battle_account.battle_status {
+        BattleStatus::ResultRecorded => {
+            if Clock::get()?.unix_timestamp < ctx.accounts.battle_account.dispute_deadline {
+                return Err(ScoogiBattleError::DisputeWindowOpen.into());
+            }
+
+            ctx.accounts.battle_account.battle_status = BattleStatus::Completed;
+
+            let referrers = utils::referrer_accounts(
+                &ctx.accounts.battle_account,
+                ctx.accounts.player_one_referrer.clone(),
+                ctx.accounts.player_two_referrer.clone(),
+            )?;
+
+            let escrow = utils::SolEscrow {
+                sol_vault: ctx.accounts.sol_vault.to_account_info(),
+            };
+
+            let payout = escrow.pay_out(
+                escrow.pot()?,
+                &ctx.accounts.battle_account.fee_schedule,
+                &ctx.accounts.winner,
+                &ctx.accounts.treasury,
+                &referrers,
+                &ctx.accounts.incinerator,
+            )?;
+
+            emit_cpi!(BattleSettled::new(&ctx.accounts.battle_account, &payout));
+            emit_cpi!(utils::record_profiles(
+                &ctx.accounts.battle_account,
+                &mut ctx.accounts.player_one_profile,
+                &mut ctx.accounts.player_two_profile,
+            ));
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    Ok(())
+}
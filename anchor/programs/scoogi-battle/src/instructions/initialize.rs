@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
-use crate::{constants, Admin};
+use crate::{constants, Admin, FeeSchedule};
 
 #[derive(Accounts)]
 
@@ -20,16 +20,36 @@ pub struct Initialize<'info> {
 
     pub mint: InterfaceAccount<'info, Mint>,
 
+    #[account(token::mint = mint)]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: the game server key allowed to settle battles
+    pub result_authority: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn initialize_ix(ctx: Context<Initialize>, burn_fee_bps: u64, battle_price: u64) -> Result<()> {
-    ctx.accounts.admin_account.battle_price = battle_price
-        .checked_mul((10u64).pow(ctx.accounts.mint.decimals as u32))
-        .unwrap();
+    FeeSchedule {
+        burn_fee_bps,
+        ..FeeSchedule::default()
+    }
+    .validate()?;
+
+    // no stake bounds until the admin sets some
+    ctx.accounts.admin_account.min_stake = 0;
+    ctx.accounts.admin_account.max_stake = u64::MAX;
+    ctx.accounts.admin_account.battle_price =
+        Admin::to_base_units(battle_price, ctx.accounts.mint.decimals)?;
     ctx.accounts.admin_account.burn_fee_bps = burn_fee_bps;
+    ctx.accounts.admin_account.version = constants::ADMIN_VERSION;
     ctx.accounts.admin_account.admin = ctx.accounts.admin.key();
+    ctx.accounts.admin_account.config_manager = ctx.accounts.admin.key();
+    ctx.accounts.admin_account.treasury_authority = ctx.accounts.admin.key();
+    ctx.accounts.admin_account.pauser = ctx.accounts.admin.key();
     ctx.accounts.admin_account.mint = ctx.accounts.mint.key();
+    ctx.accounts.admin_account.result_authority = ctx.accounts.result_authority.key();
+    ctx.accounts.admin_account.treasury_token_account = ctx.accounts.treasury_token_account.key();
 
     Ok(())
 }
@@ -5,7 +5,10 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::{constants, Admin, Battle, BattleStatus, ScoogiBattleError};
+use crate::{
+    constants, Admin, Battle, BattleJoined, BattleStatus, PlayerProfile, ScoogiBattleError,
+};
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(battle_id: u64)]
 pub struct JoinQuickBattle<'info> {
@@ -15,12 +18,16 @@ pub struct JoinQuickBattle<'info> {
     /// CHECK: passed in here for use in the seeds
     pub player_one: AccountInfo<'info>,
 
-    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = mint)]
+    #[account(seeds = [constants::ADMIN_SEED], bump)]
     pub admin_account: Account<'info, Admin>,
 
     #[account(
         mut,
-        seeds = [constants::BATTLE_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         has_one = player_one,
     )]
@@ -36,7 +43,11 @@ pub struct JoinQuickBattle<'info> {
 
     #[account(
         mut,
-        seeds = [constants::TOKEN_ACCOUNT_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::TOKEN_ACCOUNT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         token::mint = mint,
         token::authority = battle_token_account,
@@ -44,23 +55,56 @@ pub struct JoinQuickBattle<'info> {
     )]
     pub battle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(constraint = mint.key() == admin_account.mint)]
+    #[account(constraint = mint.key() == battle_account.mint)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
+    #[account(
+        init_if_needed,
+        payer = player_two,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, player_two.key().as_ref()],
+        bump
+    )]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn join_battle_ix(ctx: Context<JoinQuickBattle>, battle_id: u64) -> Result<()> {
-    if ctx.accounts.battle_account.battle_id != battle_id {
-        return Err(ScoogiBattleError::InvalidBattleId.into());
+pub fn join_battle_ix(
+    ctx: Context<JoinQuickBattle>,
+    _battle_id: u64,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts
+        .admin_account
+        .require_unpaused(constants::PAUSE_JOIN)?;
+
+    let referrer = referrer.unwrap_or_default();
+    if referrer == ctx.accounts.player_two.key() {
+        return Err(ScoogiBattleError::InvalidReferrer.into());
     }
 
+    ctx.accounts
+        .player_two_profile
+        .open(ctx.accounts.player_two.key());
+
     match ctx.accounts.battle_account.battle_status {
         BattleStatus::Pending => {
+            ctx.accounts
+                .battle_account
+                .require_invited(&ctx.accounts.player_two.key())?;
+            ctx.accounts
+                .battle_account
+                .require_rating_in_band(ctx.accounts.player_two_profile.rating)?;
+
             ctx.accounts.battle_account.player_two = ctx.accounts.player_two.key();
             ctx.accounts.battle_account.battle_status = BattleStatus::InProgress;
+            ctx.accounts.battle_account.joined_at = Clock::get()?.unix_timestamp;
+            ctx.accounts.battle_account.player_two_referrer = referrer;
+            ctx.accounts.battle_account.player_two_deposit =
+                ctx.accounts.battle_account.stake_amount;
 
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_accounts = TransferChecked {
@@ -73,12 +117,14 @@ pub fn join_battle_ix(ctx: Context<JoinQuickBattle>, battle_id: u64) -> Result<(
 
             token::transfer_checked(
                 cpi_ctx,
-                ctx.accounts.admin_account.battle_price,
+                ctx.accounts.battle_account.stake_amount,
                 ctx.accounts.mint.decimals,
             )?;
         }
         _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
     }
 
+    emit_cpi!(BattleJoined::new(&ctx.accounts.battle_account));
+
     Ok(())
 }
@@ -0,0 +1,105 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    constants, Admin, Battle, BattleJoined, BattleStatus, PlayerProfile, ScoogiBattleError,
+    SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct JoinSolBattle<'info> {
+    #[account(mut, signer)]
+    pub player_two: Signer<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_one: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump)]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    #[account(
+        init_if_needed,
+        payer = player_two,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, player_two.key().as_ref()],
+        bump
+    )]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn join_sol_battle_ix(
+    ctx: Context<JoinSolBattle>,
+    _battle_id: u64,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    ctx.accounts
+        .admin_account
+        .require_unpaused(constants::PAUSE_JOIN)?;
+
+    let referrer = referrer.unwrap_or_default();
+    if referrer == ctx.accounts.player_two.key() {
+        return Err(ScoogiBattleError::InvalidReferrer.into());
+    }
+
+    ctx.accounts
+        .player_two_profile
+        .open(ctx.accounts.player_two.key());
+
+    match ctx.accounts.battle_account.battle_status {
+        BattleStatus::Pending => {
+            ctx.accounts
+                .battle_account
+                .require_invited(&ctx.accounts.player_two.key())?;
+            ctx.accounts
+                .battle_account
+                .require_rating_in_band(ctx.accounts.player_two_profile.rating)?;
+
+            ctx.accounts.battle_account.player_two = ctx.accounts.player_two.key();
+            ctx.accounts.battle_account.battle_status = BattleStatus::InProgress;
+            ctx.accounts.battle_account.joined_at = Clock::get()?.unix_timestamp;
+            ctx.accounts.battle_account.player_two_referrer = referrer;
+            ctx.accounts.battle_account.player_two_deposit =
+                ctx.accounts.battle_account.stake_amount;
+
+            let cpi_program = ctx.accounts.system_program.to_account_info();
+            let cpi_accounts = system_program::Transfer {
+                from: ctx.accounts.player_two.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+            system_program::transfer(cpi_ctx, ctx.accounts.battle_account.stake_amount)?;
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    emit_cpi!(BattleJoined::new(&ctx.accounts.battle_account));
+
+    Ok(())
+}
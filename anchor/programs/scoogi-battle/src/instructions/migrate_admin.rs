@@ -0,0 +1,104 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{constants, Admin, ScoogiBattleError};
+
+#[derive(Accounts)]
+pub struct MigrateAdmin<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: read by hand since an older layout does not deserialize as `Admin`
+    #[account(mut, seeds = [constants::ADMIN_SEED], bump, owner = crate::ID)]
+    pub admin_account: AccountInfo<'info>,
+
+    /// used when the account predates the treasury, must hold the admin's mint
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: the game server key allowed to settle battles, used when the account
+    /// predates the result authority
+    pub result_authority: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows an admin account written by an older program to the current layout,
+/// the admin pays for the extra rent. New fields read as zero, the ones where
+/// zero is not a usable default are filled in here.
+pub fn migrate_admin_ix(ctx: Context<MigrateAdmin>) -> Result<()> {
+    {
+        let data = ctx.accounts.admin_account.try_borrow_data()?;
+
+        if data.len() < 8 + 32 || data[..8] != Admin::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        // the admin is the first field in every layout
+        if data[8..8 + 32] != ctx.accounts.admin.key().to_bytes() {
+            return Err(ScoogiBattleError::Unauthorized.into());
+        }
+    }
+
+    let size = Admin::size();
+    if ctx.accounts.admin_account.data_len() < size {
+        let rent = Rent::get()?
+            .minimum_balance(size)
+            .saturating_sub(ctx.accounts.admin_account.lamports());
+
+        if rent > 0 {
+            let cpi_program = ctx.accounts.system_program.to_account_info();
+            let cpi_accounts = system_program::Transfer {
+                from: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.admin_account.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+            system_program::transfer(cpi_ctx, rent)?;
+        }
+
+        ctx.accounts.admin_account.realloc(size, true)?;
+    }
+
+    let mut admin_account = {
+        let data = ctx.accounts.admin_account.try_borrow_data()?;
+        Admin::try_deserialize(&mut &data[..])?
+    };
+
+    if admin_account.version < constants::ADMIN_VERSION {
+        let admin = admin_account.admin;
+
+        // accounts from before stake bounds have a maximum stake of zero
+        if admin_account.max_stake == 0 {
+            admin_account.max_stake = u64::MAX;
+        }
+        // accounts from before roles leave every role with the admin
+        // accounts from before the treasury and result authority have neither, and
+        // battles created without them could never be paid out
+        if admin_account.treasury_token_account == Pubkey::default() {
+            if ctx.accounts.treasury_token_account.mint != admin_account.mint {
+                return Err(ErrorCode::ConstraintTokenMint.into());
+            }
+
+            admin_account.treasury_token_account = ctx.accounts.treasury_token_account.key();
+        }
+        if admin_account.result_authority == Pubkey::default() {
+            admin_account.result_authority = ctx.accounts.result_authority.key();
+        }
+        for role in [
+            &mut admin_account.config_manager,
+            &mut admin_account.treasury_authority,
+            &mut admin_account.pauser,
+        ] {
+            if *role == Pubkey::default() {
+                *role = admin;
+            }
+        }
+
+        admin_account.version = constants::ADMIN_VERSION;
+    }
+
+    let mut data = ctx.accounts.admin_account.try_borrow_mut_data()?;
+    admin_account.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
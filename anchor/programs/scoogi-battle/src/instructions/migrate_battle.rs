@@ -0,0 +1,68 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+
+use crate::{constants, Battle};
+
+#[derive(Accounts)]
+pub struct MigrateBattle<'info> {
+    #[account(mut, signer)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read by hand since an older layout does not deserialize as `Battle`
+    #[account(mut, owner = crate::ID)]
+    pub battle_account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a battle written by an older program to the current layout so it can be
+/// joined, settled or refunded again, the payer covers the extra rent. Anyone can
+/// call it since it only fills in defaults.
+pub fn migrate_battle_ix(ctx: Context<MigrateBattle>) -> Result<()> {
+    {
+        let data = ctx.accounts.battle_account.try_borrow_data()?;
+
+        if data.len() < 8 || data[..8] != Battle::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+    }
+
+    let size = Battle::size();
+    if ctx.accounts.battle_account.data_len() < size {
+        let rent = Rent::get()?
+            .minimum_balance(size)
+            .saturating_sub(ctx.accounts.battle_account.lamports());
+
+        if rent > 0 {
+            let cpi_program = ctx.accounts.system_program.to_account_info();
+            let cpi_accounts = system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.battle_account.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+            system_program::transfer(cpi_ctx, rent)?;
+        }
+
+        ctx.accounts.battle_account.realloc(size, true)?;
+    }
+
+    let mut battle_account = {
+        let data = ctx.accounts.battle_account.try_borrow_data()?;
+        Battle::try_deserialize(&mut &data[..])?
+    };
+
+    if battle_account.version < constants::BATTLE_VERSION {
+        // battles from before version 2 never recorded their deposits
+        let (player_one_deposit, player_two_deposit) = battle_account.deposits();
+        battle_account.player_one_deposit = player_one_deposit;
+        battle_account.player_two_deposit = player_two_deposit;
+
+        // battles from before version 3 read `invited_opponent` as zero, open to anyone
+        battle_account.version = constants::BATTLE_VERSION;
+    }
+
+    let mut data = ctx.accounts.battle_account.try_borrow_mut_data()?;
+    battle_account.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+
+use crate::{constants, PlayerProfile};
+
+#[derive(Accounts)]
+pub struct MigratePlayerProfile<'info> {
+    #[account(mut, signer)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: read by hand since an older layout does not deserialize as `PlayerProfile`
+    #[account(mut, owner = crate::ID)]
+    pub player_profile: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a profile written by an older program to the current layout so its
+/// player's battles can settle again, the payer covers the extra rent. Anyone
+/// can call it since it only fills in defaults.
+pub fn migrate_player_profile_ix(ctx: Context<MigratePlayerProfile>) -> Result<()> {
+    let old_size = {
+        let data = ctx.accounts.player_profile.try_borrow_data()?;
+
+        if data.len() < 8 || data[..8] != PlayerProfile::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        data.len()
+    };
+
+    let size = PlayerProfile::size();
+    if old_size < size {
+        let rent = Rent::get()?
+            .minimum_balance(size)
+            .saturating_sub(ctx.accounts.player_profile.lamports());
+
+        if rent > 0 {
+            let cpi_program = ctx.accounts.system_program.to_account_info();
+            let cpi_accounts = system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.player_profile.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+            system_program::transfer(cpi_ctx, rent)?;
+        }
+
+        ctx.accounts.player_profile.realloc(size, true)?;
+    }
+
+    let mut player_profile = {
+        let data = ctx.accounts.player_profile.try_borrow_data()?;
+        PlayerProfile::try_deserialize(&mut &data[..])?
+    };
+
+    if player_profile.version < constants::PLAYER_PROFILE_VERSION {
+        // the first layout ended at `total_won`, before ratings
+        if old_size < 8 + 32 + 8 + 8 + 8 + 8 + 4 {
+            player_profile.rating = constants::DEFAULT_RATING;
+        }
+
+        // totals from before version 2 mixed the units of every mint wagered
+        player_profile.retired = [0; 16];
+
+        player_profile.version = constants::PLAYER_PROFILE_VERSION;
+    }
+
+    let mut data = ctx.accounts.player_profile.try_borrow_mut_data()?;
+    player_profile.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -1,19 +1,89 @@
+mod accept_admin;
+mod add_mint_config;
+mod add_sol_config;
+mod admin_sol_withdraw;
 mod admin_withdraw;
+mod apply_config;
+mod apply_mint_config;
+mod attest_battle_result;
+mod cancel_admin_transfer;
 mod create_battle;
+mod create_sol_battle;
+mod decline_battle;
+mod decline_sol_battle;
+mod dispute_battle_result;
+mod expire_battle;
+mod expire_sol_battle;
+mod finalize_battle;
+mod finalize_sol_battle;
 mod initialize;
 mod join_battle;
+mod join_sol_battle;
+mod migrate_admin;
+mod migrate_battle;
+mod migrate_player_profile;
+mod propose_admin;
 mod record_battle_result;
+mod record_sol_battle_result;
+mod remove_mint_config;
+mod resolve_dispute;
+mod resolve_sol_dispute;
+mod set_paused;
 mod update_battle_price;
+mod update_battle_timeout;
 mod update_burn_fee_bps;
+mod update_config_delay;
+mod update_dispute_window;
+mod update_fee_schedule;
 mod update_mint;
+mod update_result_authority;
+mod update_roles;
+mod update_stake_bounds;
+mod update_treasury;
 mod withdraw_from_battle;
+mod withdraw_from_sol_battle;
 
+pub use accept_admin::*;
+pub use add_mint_config::*;
+pub use add_sol_config::*;
+pub use admin_sol_withdraw::*;
 pub use admin_withdraw::*;
+pub use apply_config::*;
+pub use apply_mint_config::*;
+pub use attest_battle_result::*;
+pub use cancel_admin_transfer::*;
 pub use create_battle::*;
+pub use create_sol_battle::*;
+pub use decline_battle::*;
+pub use decline_sol_battle::*;
+pub use dispute_battle_result::*;
+pub use expire_battle::*;
+pub use expire_sol_battle::*;
+pub use finalize_battle::*;
+pub use finalize_sol_battle::*;
 pub use initialize::*;
 pub use join_battle::*;
+pub use join_sol_battle::*;
+pub use migrate_admin::*;
+pub use migrate_battle::*;
+pub use migrate_player_profile::*;
+pub use propose_admin::*;
 pub use record_battle_result::*;
+pub use record_sol_battle_result::*;
+pub use remove_mint_config::*;
+pub use resolve_dispute::*;
+pub use resolve_sol_dispute::*;
+pub use set_paused::*;
 pub use update_battle_price::*;
+pub use update_battle_timeout::*;
 pub use update_burn_fee_bps::*;
+pub use update_config_delay::*;
+pub use update_dispute_window::*;
+pub use update_fee_schedule::*;
 pub use update_mint::*;
+pub use update_result_authority::*;
+pub use update_roles::*;
+pub use update_stake_bounds::*;
+pub use update_treasury::*;
 pub use withdraw_from_battle::*;
+pub use withdraw_from_sol_battle::*;
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    /// CHECK: the key taking over as admin once it accepts
+    pub pending_admin: AccountInfo<'info>,
+}
+
+/// Starts handing the admin over, replacing any earlier proposal
+pub fn propose_admin_ix(ctx: Context<ProposeAdmin>) -> Result<()> {
+    ctx.accounts.admin_account.pending_admin = ctx.accounts.pending_admin.key();
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.admin.key(),
+    });
+
+    Ok(())
+}
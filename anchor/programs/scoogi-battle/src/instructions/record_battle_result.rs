@@ -1,17 +1,27 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::sysvar};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_2022 as token,
-    token_interface::{CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{constants, Admin, Battle, BattleStatus, ScoogiBattleError};
+use crate::{
+    constants, utils, Admin, Battle, BattleResultRecorded, BattleSettled, PlayerProfile,
+    ScoogiBattleError,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(battle_result: u8, battle_id: u64)]
 pub struct RecordBattleResult<'info> {
     #[account(mut, signer)]
-    winner: Signer<'info>,
+    pub payer: Signer<'info>,
+
+    /// CHECK: for oracle battles, must either sign the transaction or have signed
+    /// the result in an Ed25519 instruction right before this one
+    pub result_authority: AccountInfo<'info>,
+
+    /// CHECK: checked against the battle result in the instruction
+    pub winner: AccountInfo<'info>,
 
     /// CHECK: passed in here for use in the seeds
     pub player_one: AccountInfo<'info>,
@@ -19,25 +29,28 @@ pub struct RecordBattleResult<'info> {
     /// CHECK: passed in here for use in the seeds
     pub player_two: AccountInfo<'info>,
 
-    /// CHECK: passed in here for use in the seeds
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
     pub admin: AccountInfo<'info>,
 
-    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = mint)]
-    pub admin_account: Account<'info, Admin>,
-
     #[account(
-        init_if_needed,
-        payer = winner,
-        associated_token::mint = mint,
-        associated_token::authority = admin,
-        associated_token::token_program = token_program
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = admin,
+        has_one = result_authority
     )]
-    pub admin_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(mut, address = battle_account.treasury_token_account)]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        close = admin,
-        seeds = [constants::BATTLE_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         has_one = player_one,
     )]
@@ -45,7 +58,11 @@ pub struct RecordBattleResult<'info> {
 
     #[account(
         mut,
-        seeds = [constants::TOKEN_ACCOUNT_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::TOKEN_ACCOUNT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         token::mint = mint,
         token::authority = battle_token_account,
@@ -55,16 +72,56 @@ pub struct RecordBattleResult<'info> {
 
     #[account(
         init_if_needed,
-        payer = winner,
+        payer = payer,
         associated_token::mint = mint,
         associated_token::authority = winner,
         associated_token::token_program = token_program
     )]
     pub winner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(constraint = mint.key() == admin_account.mint)]
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = player_one_referrer_token_account.owner == battle_account.player_one_referrer
+            @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_one_referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = player_two_referrer_token_account.owner == battle_account.player_two_referrer
+            @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_two_referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, constraint = mint.key() == battle_account.mint)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// CHECK: the instructions sysvar, used to read the Ed25519 instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_one.as_ref()],
+        bump
+    )]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_two.as_ref()],
+        bump
+    )]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -75,100 +132,68 @@ pub fn record_battle_result_ix(
     battle_result: u8,
     battle_id: u64,
 ) -> Result<()> {
-    if ctx.accounts.battle_account.battle_id != battle_id {
-        return Err(ScoogiBattleError::InvalidBattleId.into());
-    }
-
-    match ctx.accounts.battle_account.battle_status {
-        BattleStatus::InProgress => {
-            ctx.accounts.battle_account.battle_status = BattleStatus::Completed;
-
-            ctx.accounts.battle_account.winner = match battle_result {
-                0 => {
-                    if ctx.accounts.winner.key() != ctx.accounts.battle_account.player_one {
-                        return Err(ScoogiBattleError::Unauthorized.into());
-                    }
-
-                    ctx.accounts.battle_account.player_one
-                }
-                1 => {
-                    if ctx.accounts.winner.key() != ctx.accounts.battle_account.player_two {
-                        return Err(ScoogiBattleError::Unauthorized.into());
-                    }
-
-                    ctx.accounts.battle_account.player_two
-                }
-                _ => return Err(ScoogiBattleError::InvalidBattleResult.into()),
-            };
-
-            let player_one_key = ctx.accounts.player_one.key();
-            let burn_amount = ctx
-                .accounts
-                .admin_account
-                .burn_fee_bps
-                .checked_mul(
-                    ctx.accounts
-                        .admin_account
-                        .battle_price
-                        .checked_mul(2)
-                        .unwrap(),
-                )
-                .unwrap()
-                .checked_div(10_000)
-                .unwrap();
-
-            let winner_amount = ctx
-                .accounts
-                .battle_token_account
-                .amount
-                .checked_sub(burn_amount)
-                .unwrap();
-
-            let bump = ctx.bumps.battle_token_account;
-            let signer_seeds: &[&[&[u8]]] = &[&[
-                constants::TOKEN_ACCOUNT_SEED,
-                player_one_key.as_ref(),
-                &[bump],
-            ]];
-
-            // Send to winner
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_accounts = TransferChecked {
-                from: ctx.accounts.battle_token_account.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.winner_token_account.to_account_info(),
-                authority: ctx.accounts.battle_token_account.to_account_info(),
-            };
-            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-
-            token::transfer_checked(cpi_ctx, winner_amount, ctx.accounts.mint.decimals)?;
-
-            // Send to admin account so tokens can be burned
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-
-            let cpi_accounts = TransferChecked {
-                from: ctx.accounts.battle_token_account.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.admin_token_account.to_account_info(),
-                authority: ctx.accounts.battle_token_account.to_account_info(),
-            };
-            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-
-            token::transfer_checked(cpi_ctx, burn_amount, ctx.accounts.mint.decimals)?;
-
-            // Close the battle token account
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_accounts = CloseAccount {
-                account: ctx.accounts.battle_token_account.to_account_info(),
-                destination: ctx.accounts.admin.to_account_info(),
-                authority: ctx.accounts.battle_token_account.to_account_info(),
-            };
-            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-
-            token::close_account(cpi_ctx)?;
-        }
-        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    let settled = utils::record_result(
+        &mut ctx.accounts.battle_account,
+        battle_result,
+        &ctx.accounts.winner.key(),
+        &ctx.accounts.result_authority,
+        &ctx.accounts.instructions_sysvar,
+        ctx.program_id,
+        ctx.accounts.admin_account.dispute_window_seconds,
+    )?;
+    if !settled {
+        emit_cpi!(BattleResultRecorded::new(&ctx.accounts.battle_account));
+        return Ok(());
     }
 
-    Ok(())
+    let player_one_key = ctx.accounts.player_one.key();
+    let battle_id_bytes = battle_id.to_le_bytes();
+    let bump = ctx.bumps.battle_token_account;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        constants::TOKEN_ACCOUNT_SEED,
+        player_one_key.as_ref(),
+        battle_id_bytes.as_ref(),
+        &[bump],
+    ]];
+
+    let referrer_token_accounts = utils::referrer_accounts(
+        &ctx.accounts.battle_account,
+        ctx.accounts
+            .player_one_referrer_token_account
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        ctx.accounts
+            .player_two_referrer_token_account
+            .as_ref()
+            .map(|account| account.to_account_info()),
+    )?;
+
+    let escrow = utils::BattleEscrow {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        battle_token_account: ctx.accounts.battle_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        decimals: ctx.accounts.mint.decimals,
+        signer_seeds,
+    };
+
+    let payout = escrow.pay_out(
+        ctx.accounts.battle_token_account.amount,
+        &ctx.accounts.battle_account.fee_schedule,
+        &ctx.accounts.winner_token_account.to_account_info(),
+        &ctx.accounts.treasury_token_account.to_account_info(),
+        &referrer_token_accounts,
+    )?;
+    escrow.close(&ctx.accounts.admin.to_account_info())?;
+
+    emit_cpi!(BattleSettled::new(&ctx.accounts.battle_account, &payout));
+    emit_cpi!(utils::record_profiles(
+        &ctx.accounts.battle_account,
+        &mut ctx.accounts.player_one_profile,
+        &mut ctx.accounts.player_two_profile,
+    ));
+
+    // the battle is only closed once it is paid out
+    ctx.accounts
+        .battle_account
+        .close(ctx.accounts.admin.to_account_info())
 }
@@ -0,0 +1,166 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{incinerator, sysvar},
+};
+
+use crate::{
+    constants, utils, Admin, Battle, BattleResultRecorded, BattleSettled, PlayerProfile,
+    ScoogiBattleError, SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_result: u8, battle_id: u64)]
+pub struct RecordSolBattleResult<'info> {
+    #[account(mut, signer)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: for oracle battles, must either sign the transaction or have signed
+    /// the result in an Ed25519 instruction right before this one
+    pub result_authority: AccountInfo<'info>,
+
+    /// CHECK: checked against the battle result in the instruction
+    #[account(mut)]
+    pub winner: AccountInfo<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    pub player_two: AccountInfo<'info>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = admin,
+        has_one = result_authority
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    /// CHECK: the treasury wallet snapshotted onto the battle
+    #[account(mut, address = battle_account.treasury_token_account)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// CHECK: checked against the battle's referrer
+    #[account(
+        mut,
+        address = battle_account.player_one_referrer @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_one_referrer: Option<AccountInfo<'info>>,
+
+    /// CHECK: checked against the battle's referrer
+    #[account(
+        mut,
+        address = battle_account.player_two_referrer @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_two_referrer: Option<AccountInfo<'info>>,
+
+    /// CHECK: receives the burned lamports
+    #[account(mut, address = incinerator::ID)]
+    pub incinerator: AccountInfo<'info>,
+
+    /// CHECK: the instructions sysvar, used to read the Ed25519 instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_one.as_ref()],
+        bump
+    )]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_two.as_ref()],
+        bump
+    )]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn record_sol_battle_result_ix(
+    ctx: Context<RecordSolBattleResult>,
+    battle_result: u8,
+    _battle_id: u64,
+) -> Result<()> {
+    let settled = utils::record_result(
+        &mut ctx.accounts.battle_account,
+        battle_result,
+        &ctx.accounts.winner.key(),
+        &ctx.accounts.result_authority,
+        &ctx.accounts.instructions_sysvar,
+        ctx.program_id,
+        ctx.accounts.admin_account.dispute_window_seconds,
+    )?;
+    if !settled {
+        emit_cpi!(BattleResultRecorded::new(&ctx.accounts.battle_account));
+        return Ok(());
+    }
+
+    let referrers = utils::referrer_accounts(
+        &ctx.accounts.battle_account,
+        ctx.accounts.player_one_referrer.clone(),
+        ctx.accounts.player_two_referrer.clone(),
+    )?;
+
+    let escrow = utils::SolEscrow {
+        sol_vault: ctx.accounts.sol_vault.to_account_info(),
+    };
+
+    let payout = escrow.pay_out(
+        escrow.pot()?,
+        &ctx.accounts.battle_account.fee_schedule,
+        &ctx.accounts.winner,
+        &ctx.accounts.treasury,
+        &referrers,
+        &ctx.accounts.incinerator,
+    )?;
+
+    emit_cpi!(BattleSettled::new(&ctx.accounts.battle_account, &payout));
+    emit_cpi!(utils::record_profiles(
+        &ctx.accounts.battle_account,
+        &mut ctx.accounts.player_one_profile,
+        &mut ctx.accounts.player_two_profile,
+    ));
+
+    // the battle and its vault are only closed once they are paid out
+    ctx.accounts
+        .sol_vault
+        .close(ctx.accounts.admin.to_account_info())?;
+    ctx.accounts
+        .battle_account
+        .close(ctx.accounts.admin.to_account_info())
+}
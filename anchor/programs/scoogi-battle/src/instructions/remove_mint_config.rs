@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated, MintConfig, PendingMintConfig, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveMintConfig<'info> {
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
+
+    #[account(
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(seeds = [constants::MINT_CONFIG_SEED, mint_config.mint.as_ref()], bump)]
+    pub mint_config: Account<'info, MintConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = config_manager,
+        space = PendingMintConfig::size(),
+        seeds = [constants::PENDING_MINT_CONFIG_SEED, mint_config.mint.as_ref()],
+        bump
+    )]
+    pub pending_mint_config: Account<'info, PendingMintConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Stops new battles in the mint once the config delay has passed and
+/// `apply_mint_config` closes its config, battles already created keep their
+/// snapshotted terms
+pub fn remove_mint_config_ix(ctx: Context<RemoveMintConfig>) -> Result<()> {
+    let effective_at = ctx.accounts.admin_account.config_effective_at()?;
+    ctx.accounts
+        .pending_mint_config
+        .queue_removal(ctx.accounts.mint_config.mint, effective_at);
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.pending_mint_config.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
+
+    Ok(())
+}
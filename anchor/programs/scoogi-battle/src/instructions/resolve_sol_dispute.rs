@@ -0,0 +1,164 @@
+use anchor_lang::{prelude::*, solana_program::incinerator};
+
+use crate::{
+    constants, utils, Admin, Battle, BattleCancelled, BattleSettled, BattleStatus, CancelReason,
+    DisputeResolution, PlayerProfile, ScoogiBattleError, SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct ResolveSolDispute<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: passed in here for use in the seeds
+    #[account(mut)]
+    pub player_one: AccountInfo<'info>,
+
+    /// CHECK: checked against the battle account
+    #[account(mut)]
+    pub player_two: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    /// CHECK: the treasury wallet snapshotted onto the battle
+    #[account(mut, address = battle_account.treasury_token_account)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+        has_one = player_two
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    /// CHECK: checked against the battle's referrer
+    #[account(
+        mut,
+        address = battle_account.player_one_referrer @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_one_referrer: Option<AccountInfo<'info>>,
+
+    /// CHECK: checked against the battle's referrer
+    #[account(
+        mut,
+        address = battle_account.player_two_referrer @ ScoogiBattleError::InvalidReferrer
+    )]
+    pub player_two_referrer: Option<AccountInfo<'info>>,
+
+    /// CHECK: receives the burned lamports
+    #[account(mut, address = incinerator::ID)]
+    pub incinerator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_one.as_ref()],
+        bump
+    )]
+    pub player_one_profile: Box<Account<'info, PlayerProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PlayerProfile::size(),
+        seeds = [constants::PLAYER_PROFILE_SEED, battle_account.player_two.as_ref()],
+        bump
+    )]
+    pub player_two_profile: Box<Account<'info, PlayerProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resolve_sol_dispute_ix(
+    ctx: Context<ResolveSolDispute>,
+    _battle_id: u64,
+    resolution: DisputeResolution,
+) -> Result<()> {
+    match ctx.accounts.battle_account.battle_status {
+        BattleStatus::Disputed => {
+            ctx.accounts.battle_account.battle_status = BattleStatus::Completed;
+
+            let referrers = utils::referrer_accounts(
+                &ctx.accounts.battle_account,
+                ctx.accounts.player_one_referrer.clone(),
+                ctx.accounts.player_two_referrer.clone(),
+            )?;
+
+            let escrow = utils::SolEscrow {
+                sol_vault: ctx.accounts.sol_vault.to_account_info(),
+            };
+
+            let pot = escrow.pot()?;
+            let fee_schedule = ctx.accounts.battle_account.fee_schedule;
+
+            let payout = match resolution {
+                DisputeResolution::PlayerOneWins => {
+                    ctx.accounts.battle_account.winner = ctx.accounts.battle_account.player_one;
+                    Some(escrow.pay_out(
+                        pot,
+                        &fee_schedule,
+                        &ctx.accounts.player_one,
+                        &ctx.accounts.treasury,
+                        &referrers,
+                        &ctx.accounts.incinerator,
+                    )?)
+                }
+                DisputeResolution::PlayerTwoWins => {
+                    ctx.accounts.battle_account.winner = ctx.accounts.battle_account.player_two;
+                    Some(escrow.pay_out(
+                        pot,
+                        &fee_schedule,
+                        &ctx.accounts.player_two,
+                        &ctx.accounts.treasury,
+                        &referrers,
+                        &ctx.accounts.incinerator,
+                    )?)
+                }
+                DisputeResolution::Refund => {
+                    escrow.refund(pot, &ctx.accounts.player_one, &ctx.accounts.player_two)?;
+                    None
+                }
+            };
+
+            if let Some(payout) = payout {
+                emit_cpi!(BattleSettled::new(&ctx.accounts.battle_account, &payout));
+                emit_cpi!(utils::record_profiles(
+                    &ctx.accounts.battle_account,
+                    &mut ctx.accounts.player_one_profile,
+                    &mut ctx.accounts.player_two_profile,
+                ));
+            } else {
+                emit_cpi!(BattleCancelled::new(
+                    &ctx.accounts.battle_account,
+                    CancelReason::Refunded
+                ));
+            }
+        }
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(mut, signer)]
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = pauser @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+}
+
+/// Sets or clears the `constants::PAUSE_*` flags in `flags`, leaving the others
+/// as they are. Refunds and settlements are never paused.
+pub fn set_paused_ix(ctx: Context<SetPaused>, flags: u8, paused: bool) -> Result<()> {
+    if paused {
+        ctx.accounts.admin_account.paused |= flags;
+    } else {
+        ctx.accounts.admin_account.paused &= !flags;
+    }
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.pauser.key(),
+    });
+
+    Ok(())
+}
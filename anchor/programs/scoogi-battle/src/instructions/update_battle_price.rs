@@ -1,24 +1,40 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
-use crate::{constants, Admin};
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateBattlePrice<'info> {
-    #[account(mut, signer, constraint = admin.key() == admin_account.admin)]
-    pub admin: Signer<'info>,
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
 
-    #[account(mut, seeds = [constants::ADMIN_SEED], bump, has_one = admin, has_one = mint)]
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized,
+        has_one = mint
+    )]
     pub admin_account: Account<'info, Admin>,
 
     #[account(constraint = mint.key() == admin_account.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
 }
 
+/// Queued behind the config delay, see `apply_config`
 pub fn update_battle_price_ix(ctx: Context<UpdateBattlePrice>, battle_price: u64) -> Result<()> {
-    ctx.accounts.admin_account.battle_price = battle_price
-        .checked_mul((10u64).pow(ctx.accounts.mint.decimals as u32))
-        .unwrap();
+    let battle_price = Admin::to_base_units(battle_price, ctx.accounts.mint.decimals)?;
+    ctx.accounts.admin_account.validate_stake(battle_price)?;
+
+    ctx.accounts
+        .admin_account
+        .queue_config(Some(battle_price), None)?;
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
 
     Ok(())
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateBattleTimeout<'info> {
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+}
+
+/// A timeout of 0 disables `expire_battle`
+pub fn update_battle_timeout_ix(
+    ctx: Context<UpdateBattleTimeout>,
+    battle_timeout_seconds: i64,
+) -> Result<()> {
+    ctx.accounts.admin_account.battle_timeout_seconds = battle_timeout_seconds.max(0);
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
+
+    Ok(())
+}
@@ -1,26 +1,40 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants, Admin};
+use crate::{constants, Admin, ConfigUpdated, FeeSchedule, ScoogiBattleError};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateBurnFeeBps<'info> {
     #[account(mut, signer)]
-    pub admin: Signer<'info>,
+    pub config_manager: Signer<'info>,
 
     #[account(
         mut,
         seeds = [constants::ADMIN_SEED],
         bump,
-        constraint = admin_account.admin == admin.key(),
-        has_one = admin
+        has_one = config_manager @ ScoogiBattleError::Unauthorized
     )]
     pub admin_account: Account<'info, Admin>,
 
     pub system_program: Program<'info, System>,
 }
 
+/// Queued behind the config delay like `update_fee_schedule`
 pub fn update_burn_fee_bps_ix(ctx: Context<UpdateBurnFeeBps>, burn_fee_bps: u64) -> Result<()> {
-    ctx.accounts.admin_account.burn_fee_bps = burn_fee_bps;
+    let fee_schedule = FeeSchedule {
+        burn_fee_bps,
+        ..ctx.accounts.admin_account.next_fee_schedule()
+    };
+    fee_schedule.validate()?;
+
+    ctx.accounts
+        .admin_account
+        .queue_config(None, Some(fee_schedule))?;
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
 
     Ok(())
 }
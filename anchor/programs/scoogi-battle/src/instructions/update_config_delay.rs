@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfigDelay<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+}
+
+/// Only the admin can change the delay, a delay of 0 applies config changes right
+/// away. Lowering it waits out the current delay and lands with `apply_config`.
+pub fn update_config_delay_ix(
+    ctx: Context<UpdateConfigDelay>,
+    config_delay_seconds: i64,
+) -> Result<()> {
+    if config_delay_seconds > constants::MAX_CONFIG_DELAY_SECONDS {
+        return Err(ScoogiBattleError::InvalidConfigDelay.into());
+    }
+
+    ctx.accounts
+        .admin_account
+        .update_config_delay(config_delay_seconds.max(0))?;
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.admin.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateDisputeWindow<'info> {
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+}
+
+/// A window of 0 pays out as soon as the result is recorded, it can't be longer than
+/// `constants::MAX_DISPUTE_WINDOW_SECONDS`
+pub fn update_dispute_window_ix(
+    ctx: Context<UpdateDisputeWindow>,
+    dispute_window_seconds: i64,
+) -> Result<()> {
+    if dispute_window_seconds > constants::MAX_DISPUTE_WINDOW_SECONDS {
+        return Err(ScoogiBattleError::InvalidDisputeWindow.into());
+    }
+
+    ctx.accounts.admin_account.dispute_window_seconds = dispute_window_seconds.max(0);
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated, FeeSchedule, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateFeeSchedule<'info> {
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized
+    )]
+    pub admin_account: Account<'info, Admin>,
+}
+
+/// Queued behind the config delay, see `apply_config`
+pub fn update_fee_schedule_ix(
+    ctx: Context<UpdateFeeSchedule>,
+    fee_schedule: FeeSchedule,
+) -> Result<()> {
+    fee_schedule.validate()?;
+
+    ctx.accounts
+        .admin_account
+        .queue_config(None, Some(fee_schedule))?;
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
+
+    Ok(())
+}
@@ -1,21 +1,54 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
-use crate::{constants, Admin};
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateMint<'info> {
-    #[account(mut, signer, constraint = admin.key() == admin_account.admin)]
-    pub admin: Signer<'info>,
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
 
-    #[account(mut, seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized
+    )]
     pub admin_account: Account<'info, Admin>,
 
+    #[account(token::mint = mint)]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // the price and bounds are set here without the config delay, which only a
+    // change of units justifies
+    #[account(constraint = mint.key() != admin_account.mint @ ScoogiBattleError::MintUnchanged)]
     pub mint: InterfaceAccount<'info, Mint>,
 }
 
-pub fn update_mint_ix(ctx: Context<UpdateMint>) -> Result<()> {
+/// Open battles snapshotted their mint and treasury and keep settling in them,
+/// only battles created afterwards use the new mint. The treasury moves with the
+/// mint so those battles can pay their treasury fee, and the price and bounds are
+/// set again in whole tokens since the old ones were in the old mint's base units.
+pub fn update_mint_ix(
+    ctx: Context<UpdateMint>,
+    battle_price: u64,
+    min_stake: u64,
+    max_stake: u64,
+) -> Result<()> {
     ctx.accounts.admin_account.mint = ctx.accounts.mint.key();
+    ctx.accounts.admin_account.treasury_token_account = ctx.accounts.treasury_token_account.key();
+    ctx.accounts.admin_account.set_terms(
+        ctx.accounts.mint.decimals,
+        battle_price,
+        min_stake,
+        max_stake,
+    )?;
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
 
     Ok(())
 }
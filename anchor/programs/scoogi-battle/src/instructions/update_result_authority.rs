@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateResultAuthority<'info> {
+    #[account(mut, signer, constraint = admin.key() == admin_account.admin)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    /// CHECK: the game server key allowed to settle battles
+    pub result_authority: AccountInfo<'info>,
+}
+
+pub fn update_result_authority_ix(ctx: Context<UpdateResultAuthority>) -> Result<()> {
+    ctx.accounts.admin_account.result_authority = ctx.accounts.result_authority.key();
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.admin.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, Admin, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRoles<'info> {
+    #[account(mut, signer)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    /// CHECK: the key allowed to change prices, fees, mints and timings
+    pub config_manager: AccountInfo<'info>,
+
+    /// CHECK: the key allowed to move the treasury and withdraw battles
+    pub treasury_authority: AccountInfo<'info>,
+
+    /// CHECK: the key allowed to pause the program in an emergency
+    pub pauser: AccountInfo<'info>,
+}
+
+/// Delegates the admin's roles, the result authority has `update_result_authority`
+pub fn update_roles_ix(ctx: Context<UpdateRoles>) -> Result<()> {
+    ctx.accounts.admin_account.config_manager = ctx.accounts.config_manager.key();
+    ctx.accounts.admin_account.treasury_authority = ctx.accounts.treasury_authority.key();
+    ctx.accounts.admin_account.pauser = ctx.accounts.pauser.key();
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.admin.key(),
+    });
+
+    Ok(())
+}
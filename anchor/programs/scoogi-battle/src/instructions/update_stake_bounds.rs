@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateStakeBounds<'info> {
+    #[account(mut, signer)]
+    pub config_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = config_manager @ ScoogiBattleError::Unauthorized,
+        has_one = mint
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Bounds are in whole tokens like `update_battle_price`, the current battle
+/// price is the default stake and has to fall within them
+pub fn update_stake_bounds_ix(
+    ctx: Context<UpdateStakeBounds>,
+    min_stake: u64,
+    max_stake: u64,
+) -> Result<()> {
+    let min_stake = Admin::bound_to_base_units(min_stake, ctx.accounts.mint.decimals)?;
+    let max_stake = Admin::bound_to_base_units(max_stake, ctx.accounts.mint.decimals)?;
+
+    if min_stake > max_stake {
+        return Err(ScoogiBattleError::StakeOutOfRange.into());
+    }
+
+    ctx.accounts.admin_account.min_stake = min_stake;
+    ctx.accounts.admin_account.max_stake = max_stake;
+
+    let battle_price = ctx.accounts.admin_account.battle_price;
+    ctx.accounts.admin_account.validate_stake(battle_price)?;
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.config_manager.key(),
+    });
+
+    Ok(())
+}
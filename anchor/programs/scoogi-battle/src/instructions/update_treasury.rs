@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{constants, Admin, ConfigUpdated, ScoogiBattleError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateTreasury<'info> {
+    #[account(mut, signer)]
+    pub treasury_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::ADMIN_SEED],
+        bump,
+        has_one = treasury_authority @ ScoogiBattleError::Unauthorized,
+        has_one = mint
+    )]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(token::mint = mint)]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+pub fn update_treasury_ix(ctx: Context<UpdateTreasury>) -> Result<()> {
+    ctx.accounts.admin_account.treasury_token_account = ctx.accounts.treasury_token_account.key();
+
+    emit_cpi!(ConfigUpdated {
+        config: ctx.accounts.admin_account.key(),
+        authority: ctx.accounts.treasury_authority.key(),
+    });
+
+    Ok(())
+}
@@ -5,8 +5,11 @@ use anchor_spl::{
     token_interface::{CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::{constants, Admin, Battle, BattleStatus, ScoogiBattleError};
+use crate::{
+    constants, Admin, Battle, BattleCancelled, BattleStatus, CancelReason, ScoogiBattleError,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(battle_id: u64)]
 pub struct WithdrawFromBattle<'info> {
@@ -16,13 +19,17 @@ pub struct WithdrawFromBattle<'info> {
     /// CHECK: passed in here for use in the seeds
     pub admin: AccountInfo<'info>,
 
-    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = mint, has_one = admin)]
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
     pub admin_account: Account<'info, Admin>,
 
     #[account(
         mut,
         close = admin,
-        seeds = [constants::BATTLE_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         has_one = player_one,
     )]
@@ -30,7 +37,11 @@ pub struct WithdrawFromBattle<'info> {
 
     #[account(
         mut,
-        seeds = [constants::TOKEN_ACCOUNT_SEED, player_one.key().as_ref()],
+        seeds = [
+            constants::TOKEN_ACCOUNT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
         bump,
         token::mint = mint,
         token::authority = battle_token_account,
@@ -47,7 +58,7 @@ pub struct WithdrawFromBattle<'info> {
     )]
     pub player_one_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(constraint = mint.key() == admin_account.mint)]
+    #[account(constraint = mint.key() == battle_account.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
 
     pub system_program: Program<'info, System>,
@@ -56,19 +67,17 @@ pub struct WithdrawFromBattle<'info> {
 }
 
 pub fn withdraw_from_battle_ix(ctx: Context<WithdrawFromBattle>, battle_id: u64) -> Result<()> {
-    if ctx.accounts.battle_account.battle_id != battle_id {
-        return Err(ScoogiBattleError::InvalidBattleId.into());
-    }
-
     match ctx.accounts.battle_account.battle_status {
         // only player one has joined the battle
         BattleStatus::Pending => {
             // transfer player one's tokens back to them
             let player_one_key = ctx.accounts.player_one.key();
+            let battle_id_bytes = battle_id.to_le_bytes();
             let bump = ctx.bumps.battle_token_account;
             let signer_seeds: &[&[&[u8]]] = &[&[
                 constants::TOKEN_ACCOUNT_SEED,
                 player_one_key.as_ref(),
+                battle_id_bytes.as_ref(),
                 &[bump],
             ]];
 
@@ -102,5 +111,10 @@ pub fn withdraw_from_battle_ix(ctx: Context<WithdrawFromBattle>, battle_id: u64)
         _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
     }
 
+    emit_cpi!(BattleCancelled::new(
+        &ctx.accounts.battle_account,
+        CancelReason::Withdrawn
+    ));
+
     Ok(())
 }
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants, utils, Admin, Battle, BattleCancelled, BattleStatus, CancelReason,
+    ScoogiBattleError, SolVault,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(battle_id: u64)]
+pub struct WithdrawFromSolBattle<'info> {
+    #[account(mut, signer)]
+    pub player_one: Signer<'info>,
+
+    /// CHECK: checked against the admin account, receives the closed accounts' rent
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(seeds = [constants::ADMIN_SEED], bump, has_one = admin)]
+    pub admin_account: Account<'info, Admin>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::BATTLE_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        has_one = player_one,
+    )]
+    pub battle_account: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            constants::SOL_VAULT_SEED,
+            player_one.key().as_ref(),
+            battle_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_from_sol_battle_ix(
+    ctx: Context<WithdrawFromSolBattle>,
+    _battle_id: u64,
+) -> Result<()> {
+    match ctx.accounts.battle_account.battle_status {
+        // only player one has joined the battle, the vault's rent goes to the admin
+        // when it is closed like a battle token account's
+        BattleStatus::Pending => {
+            let escrow = utils::SolEscrow {
+                sol_vault: ctx.accounts.sol_vault.to_account_info(),
+            };
+
+            escrow.transfer(&ctx.accounts.player_one.to_account_info(), escrow.pot()?)?;
+        }
+        // both players have joined the battle, requires admin withdrawal
+        _ => return Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+
+    emit_cpi!(BattleCancelled::new(
+        &ctx.accounts.battle_account,
+        CancelReason::Withdrawn
+    ));
+
+    Ok(())
+}
@@ -2,13 +2,17 @@ use anchor_lang::prelude::*;
 
 mod constants;
 mod errors;
+mod events;
 mod instructions;
 mod state;
+mod utils;
 
 pub use constants::*;
 pub use errors::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
+pub use utils::*;
 
 declare_id!("GM9mV2Km6iwaQsVjs5x4wb4CL2XBemS3cgZ28sCydiBR");
 
@@ -24,28 +28,165 @@ pub mod scoogi_battle {
         instructions::initialize_ix(ctx, burn_fee_bps, battle_price)
     }
 
+    pub fn migrate_admin(ctx: Context<MigrateAdmin>) -> Result<()> {
+        instructions::migrate_admin_ix(ctx)
+    }
+
+    pub fn migrate_battle(ctx: Context<MigrateBattle>) -> Result<()> {
+        instructions::migrate_battle_ix(ctx)
+    }
+
+    pub fn migrate_player_profile(ctx: Context<MigratePlayerProfile>) -> Result<()> {
+        instructions::migrate_player_profile_ix(ctx)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        instructions::propose_admin_ix(ctx)
+    }
+
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        instructions::cancel_admin_transfer_ix(ctx)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin_ix(ctx)
+    }
+
+    pub fn update_roles(ctx: Context<UpdateRoles>) -> Result<()> {
+        instructions::update_roles_ix(ctx)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, flags: u8, paused: bool) -> Result<()> {
+        instructions::set_paused_ix(ctx, flags, paused)
+    }
+
     pub fn update_burn_fee_bps(ctx: Context<UpdateBurnFeeBps>, burn_fee_bps: u64) -> Result<()> {
         instructions::update_burn_fee_bps_ix(ctx, burn_fee_bps)
     }
 
+    pub fn update_fee_schedule(
+        ctx: Context<UpdateFeeSchedule>,
+        fee_schedule: FeeSchedule,
+    ) -> Result<()> {
+        instructions::update_fee_schedule_ix(ctx, fee_schedule)
+    }
+
+    pub fn update_treasury(ctx: Context<UpdateTreasury>) -> Result<()> {
+        instructions::update_treasury_ix(ctx)
+    }
+
     pub fn update_battle_price(ctx: Context<UpdateBattlePrice>, battle_price: u64) -> Result<()> {
         instructions::update_battle_price_ix(ctx, battle_price)
     }
 
-    pub fn update_mint(ctx: Context<UpdateMint>) -> Result<()> {
-        instructions::update_mint_ix(ctx)
+    pub fn update_stake_bounds(
+        ctx: Context<UpdateStakeBounds>,
+        min_stake: u64,
+        max_stake: u64,
+    ) -> Result<()> {
+        instructions::update_stake_bounds_ix(ctx, min_stake, max_stake)
+    }
+
+    pub fn update_config_delay(
+        ctx: Context<UpdateConfigDelay>,
+        config_delay_seconds: i64,
+    ) -> Result<()> {
+        instructions::update_config_delay_ix(ctx, config_delay_seconds)
+    }
+
+    pub fn apply_config(ctx: Context<ApplyConfig>) -> Result<()> {
+        instructions::apply_config_ix(ctx)
+    }
+
+    pub fn update_mint(
+        ctx: Context<UpdateMint>,
+        battle_price: u64,
+        min_stake: u64,
+        max_stake: u64,
+    ) -> Result<()> {
+        instructions::update_mint_ix(ctx, battle_price, min_stake, max_stake)
+    }
+
+    pub fn add_mint_config(
+        ctx: Context<AddMintConfig>,
+        battle_price: u64,
+        min_stake: u64,
+        max_stake: u64,
+        fee_schedule: FeeSchedule,
+    ) -> Result<()> {
+        instructions::add_mint_config_ix(ctx, battle_price, min_stake, max_stake, fee_schedule)
+    }
+
+    pub fn apply_mint_config(ctx: Context<ApplyMintConfig>) -> Result<()> {
+        instructions::apply_mint_config_ix(ctx)
+    }
+
+    pub fn remove_mint_config(ctx: Context<RemoveMintConfig>) -> Result<()> {
+        instructions::remove_mint_config_ix(ctx)
+    }
+
+    pub fn add_sol_config(
+        ctx: Context<AddSolConfig>,
+        battle_price: u64,
+        min_stake: u64,
+        max_stake: u64,
+        fee_schedule: FeeSchedule,
+    ) -> Result<()> {
+        instructions::add_sol_config_ix(ctx, battle_price, min_stake, max_stake, fee_schedule)
+    }
+
+    pub fn update_result_authority(ctx: Context<UpdateResultAuthority>) -> Result<()> {
+        instructions::update_result_authority_ix(ctx)
+    }
+
+    pub fn update_dispute_window(
+        ctx: Context<UpdateDisputeWindow>,
+        dispute_window_seconds: i64,
+    ) -> Result<()> {
+        instructions::update_dispute_window_ix(ctx, dispute_window_seconds)
+    }
+
+    pub fn update_battle_timeout(
+        ctx: Context<UpdateBattleTimeout>,
+        battle_timeout_seconds: i64,
+    ) -> Result<()> {
+        instructions::update_battle_timeout_ix(ctx, battle_timeout_seconds)
     }
 
     pub fn admin_withdrawal(ctx: Context<AdminWithdraw>, battle_id: u64) -> Result<()> {
         instructions::admin_withdraw_ix(ctx, battle_id)
     }
 
-    pub fn create_battle(ctx: Context<CreateBattle>, battle_id: u64) -> Result<()> {
-        instructions::create_battle_ix(ctx, battle_id)
+    pub fn admin_sol_withdrawal(ctx: Context<AdminSolWithdraw>, battle_id: u64) -> Result<()> {
+        instructions::admin_sol_withdraw_ix(ctx, battle_id)
     }
 
-    pub fn join_battle(ctx: Context<JoinQuickBattle>, battle_id: u64) -> Result<()> {
-        instructions::join_battle_ix(ctx, battle_id)
+    pub fn create_battle(
+        ctx: Context<CreateBattle>,
+        battle_id: u64,
+        settlement_mode: SettlementMode,
+        referrer: Option<Pubkey>,
+        stake: Option<u64>,
+        rating_band: Option<RatingBand>,
+        invited_opponent: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_battle_ix(
+            ctx,
+            battle_id,
+            settlement_mode,
+            referrer,
+            stake,
+            rating_band,
+            invited_opponent,
+        )
+    }
+
+    pub fn join_battle(
+        ctx: Context<JoinQuickBattle>,
+        battle_id: u64,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::join_battle_ix(ctx, battle_id, referrer)
     }
 
     pub fn record_battle_result(
@@ -56,7 +197,102 @@ pub mod scoogi_battle {
         instructions::record_battle_result_ix(ctx, battle_result, battle_id)
     }
 
+    pub fn attest_battle_result(
+        ctx: Context<AttestBattleResult>,
+        battle_id: u64,
+        battle_result: u8,
+    ) -> Result<()> {
+        instructions::attest_battle_result_ix(ctx, battle_id, battle_result)
+    }
+
+    pub fn dispute_battle_result(ctx: Context<DisputeBattleResult>, battle_id: u64) -> Result<()> {
+        instructions::dispute_battle_result_ix(ctx, battle_id)
+    }
+
+    pub fn finalize_battle(ctx: Context<FinalizeBattle>, battle_id: u64) -> Result<()> {
+        instructions::finalize_battle_ix(ctx, battle_id)
+    }
+
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        battle_id: u64,
+        resolution: DisputeResolution,
+    ) -> Result<()> {
+        instructions::resolve_dispute_ix(ctx, battle_id, resolution)
+    }
+
+    pub fn expire_battle(ctx: Context<ExpireBattle>, battle_id: u64) -> Result<()> {
+        instructions::expire_battle_ix(ctx, battle_id)
+    }
+
     pub fn withdraw_from_battle(ctx: Context<WithdrawFromBattle>, battle_id: u64) -> Result<()> {
         instructions::withdraw_from_battle_ix(ctx, battle_id)
     }
+
+    pub fn decline_battle(ctx: Context<DeclineBattle>, battle_id: u64) -> Result<()> {
+        instructions::decline_battle_ix(ctx, battle_id)
+    }
+
+    pub fn create_sol_battle(
+        ctx: Context<CreateSolBattle>,
+        battle_id: u64,
+        settlement_mode: SettlementMode,
+        referrer: Option<Pubkey>,
+        stake: Option<u64>,
+        rating_band: Option<RatingBand>,
+        invited_opponent: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_sol_battle_ix(
+            ctx,
+            battle_id,
+            settlement_mode,
+            referrer,
+            stake,
+            rating_band,
+            invited_opponent,
+        )
+    }
+
+    pub fn join_sol_battle(
+        ctx: Context<JoinSolBattle>,
+        battle_id: u64,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::join_sol_battle_ix(ctx, battle_id, referrer)
+    }
+
+    pub fn record_sol_battle_result(
+        ctx: Context<RecordSolBattleResult>,
+        battle_result: u8,
+        battle_id: u64,
+    ) -> Result<()> {
+        instructions::record_sol_battle_result_ix(ctx, battle_result, battle_id)
+    }
+
+    pub fn finalize_sol_battle(ctx: Context<FinalizeSolBattle>, battle_id: u64) -> Result<()> {
+        instructions::finalize_sol_battle_ix(ctx, battle_id)
+    }
+
+    pub fn resolve_sol_dispute(
+        ctx: Context<ResolveSolDispute>,
+        battle_id: u64,
+        resolution: DisputeResolution,
+    ) -> Result<()> {
+        instructions::resolve_sol_dispute_ix(ctx, battle_id, resolution)
+    }
+
+    pub fn expire_sol_battle(ctx: Context<ExpireSolBattle>, battle_id: u64) -> Result<()> {
+        instructions::expire_sol_battle_ix(ctx, battle_id)
+    }
+
+    pub fn withdraw_from_sol_battle(
+        ctx: Context<WithdrawFromSolBattle>,
+        battle_id: u64,
+    ) -> Result<()> {
+        instructions::withdraw_from_sol_battle_ix(ctx, battle_id)
+    }
+
+    pub fn decline_sol_battle(ctx: Context<DeclineSolBattle>, battle_id: u64) -> Result<()> {
+        instructions::decline_sol_battle_ix(ctx, battle_id)
+    }
 }
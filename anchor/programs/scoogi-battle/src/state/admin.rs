@@ -1,15 +1,249 @@
 use anchor_lang::prelude::*;
 
+use crate::{FeeSchedule, MintConfig, PendingConfig, ScoogiBattleError};
+
 #[account]
 pub struct Admin {
     pub admin: Pubkey,
     pub mint: Pubkey,
     pub burn_fee_bps: u64,
     pub battle_price: u64,
+    pub result_authority: Pubkey,
+    pub dispute_window_seconds: i64,
+    pub battle_timeout_seconds: i64,
+    pub treasury_fee_bps: u64,
+    pub referrer_fee_bps: u64,
+    pub treasury_token_account: Pubkey,
+    pub min_stake: u64,
+    pub max_stake: u64,
+    /// proposed by the admin, `Pubkey::default()` when no handover is in progress
+    pub pending_admin: Pubkey,
+    /// roles the admin delegates, each defaults to the admin
+    pub config_manager: Pubkey,
+    pub treasury_authority: Pubkey,
+    pub pauser: Pubkey,
+    /// `constants::PAUSE_*` flags set by the pauser
+    pub paused: u8,
+    /// how long price and fee changes wait before `apply_config` can apply them
+    pub config_delay_seconds: i64,
+    pub pending_config: PendingConfig,
+    /// layout version, `migrate_admin` brings older accounts up to `constants::ADMIN_VERSION`
+    pub version: u8,
+    /// a lower config delay waiting out the current one, applied with `pending_config`
+    pub pending_config_delay_seconds: Option<i64>,
+    /// room for new fields without growing the account
+    pub reserved: [u8; 55],
 }
 
 impl Admin {
     pub fn size() -> usize {
-        8 + 32 + 32 + 8 + 8
+        8 + 32
+            + 32
+            + 8
+            + 8
+            + 32
+            + 8
+            + 8
+            + 8
+            + 8
+            + 32
+            + 8
+            + 8
+            + 32
+            + 32
+            + 32
+            + 32
+            + 1
+            + 8
+            + PendingConfig::size()
+            + 1
+            + (1 + 8)
+            + 55
+    }
+
+    pub fn fee_schedule(&self) -> FeeSchedule {
+        FeeSchedule {
+            burn_fee_bps: self.burn_fee_bps,
+            treasury_fee_bps: self.treasury_fee_bps,
+            referrer_fee_bps: self.referrer_fee_bps,
+        }
+    }
+
+    /// The fee schedule a fee change builds on, the queued one if there is one
+    pub fn next_fee_schedule(&self) -> FeeSchedule {
+        self.pending_config
+            .fee_schedule
+            .unwrap_or_else(|| self.fee_schedule())
+    }
+
+    /// Queues a price or fee change behind the config delay, restarting the delay for
+    /// anything already queued. Without a delay the change applies right away.
+    pub fn queue_config(
+        &mut self,
+        battle_price: Option<u64>,
+        fee_schedule: Option<FeeSchedule>,
+    ) -> Result<()> {
+        if battle_price.is_some() {
+            self.pending_config.battle_price = battle_price;
+        }
+        if fee_schedule.is_some() {
+            self.pending_config.fee_schedule = fee_schedule;
+        }
+        self.pending_config.effective_at = self.config_effective_at()?;
+
+        if self.config_delay_seconds == 0 {
+            return self.apply_config();
+        }
+
+        Ok(())
+    }
+
+    /// When a config change queued now can be applied
+    pub fn config_effective_at(&self) -> Result<i64> {
+        Clock::get()?
+            .unix_timestamp
+            .checked_add(self.config_delay_seconds)
+            .ok_or(ScoogiBattleError::InvalidConfigDelay.into())
+    }
+
+    /// Raises the config delay right away, a lower one is queued behind the current
+    /// delay since applying it straight away would let the next change skip the timelock
+    pub fn update_config_delay(&mut self, config_delay_seconds: i64) -> Result<()> {
+        if config_delay_seconds >= self.config_delay_seconds {
+            self.config_delay_seconds = config_delay_seconds;
+            self.pending_config_delay_seconds = None;
+
+            return Ok(());
+        }
+
+        self.pending_config_delay_seconds = Some(config_delay_seconds);
+        self.pending_config.effective_at = self.config_effective_at()?;
+
+        Ok(())
+    }
+
+    /// Applies the queued config change once its delay has passed, the stake bounds
+    /// may have changed since it was queued so the price is checked again
+    pub fn apply_config(&mut self) -> Result<()> {
+        if self.pending_config.effective_at == 0 {
+            return Err(ScoogiBattleError::NoPendingConfig.into());
+        }
+
+        if Clock::get()?.unix_timestamp < self.pending_config.effective_at {
+            return Err(ScoogiBattleError::ConfigTimelocked.into());
+        }
+
+        if let Some(battle_price) = self.pending_config.battle_price {
+            self.validate_stake(battle_price)?;
+            self.battle_price = battle_price;
+        }
+
+        if let Some(fee_schedule) = self.pending_config.fee_schedule {
+            fee_schedule.validate()?;
+            self.burn_fee_bps = fee_schedule.burn_fee_bps;
+            self.treasury_fee_bps = fee_schedule.treasury_fee_bps;
+            self.referrer_fee_bps = fee_schedule.referrer_fee_bps;
+        }
+
+        if let Some(config_delay_seconds) = self.pending_config_delay_seconds.take() {
+            self.config_delay_seconds = config_delay_seconds;
+        }
+
+        self.pending_config = PendingConfig::default();
+
+        Ok(())
+    }
+
+    /// The terms for wagering the default mint, which needs no mint config of its own
+    pub fn mint_config(&self) -> MintConfig {
+        MintConfig {
+            mint: self.mint,
+            battle_price: self.battle_price,
+            min_stake: self.min_stake,
+            max_stake: self.max_stake,
+            fee_schedule: self.fee_schedule(),
+            treasury_token_account: self.treasury_token_account,
+        }
+    }
+
+    /// Hands the admin to `new_admin` along with every role the outgoing admin
+    /// still held, roles delegated to other keys stay where they are
+    pub fn hand_over(&mut self, new_admin: Pubkey) {
+        let old_admin = self.admin;
+
+        for role in [
+            &mut self.config_manager,
+            &mut self.treasury_authority,
+            &mut self.pauser,
+        ] {
+            if *role == old_admin {
+                *role = new_admin;
+            }
+        }
+
+        self.admin = new_admin;
+        self.pending_admin = Pubkey::default();
+    }
+
+    /// Errors when any of the `constants::PAUSE_*` flags in `flags` is set
+    pub fn require_unpaused(&self, flags: u8) -> Result<()> {
+        if self.paused & flags != 0 {
+            return Err(ScoogiBattleError::ProgramPaused.into());
+        }
+
+        Ok(())
+    }
+
+    /// Converts a price in whole tokens to base units of a mint with `decimals`
+    pub fn to_base_units(price: u64, decimals: u8) -> Result<u64> {
+        if price == 0 {
+            return Err(ScoogiBattleError::PriceZero.into());
+        }
+
+        Admin::bound_to_base_units(price, decimals)
+    }
+
+    /// Converts a stake bound in whole tokens to base units like `to_base_units`,
+    /// a minimum stake of 0 leaves stakes unbounded below
+    pub fn bound_to_base_units(bound: u64, decimals: u8) -> Result<u64> {
+        10u64
+            .checked_pow(decimals as u32)
+            .and_then(|unit| bound.checked_mul(unit))
+            .ok_or(ScoogiBattleError::PriceOverflow.into())
+    }
+
+    /// Sets the default mint's price and stake bounds from amounts in whole tokens of
+    /// a mint with `decimals`, dropping a queued price that was in the old units
+    pub fn set_terms(
+        &mut self,
+        decimals: u8,
+        battle_price: u64,
+        min_stake: u64,
+        max_stake: u64,
+    ) -> Result<()> {
+        let min_stake = Admin::bound_to_base_units(min_stake, decimals)?;
+        let max_stake = Admin::bound_to_base_units(max_stake, decimals)?;
+
+        if min_stake > max_stake {
+            return Err(ScoogiBattleError::StakeOutOfRange.into());
+        }
+
+        self.battle_price = Admin::to_base_units(battle_price, decimals)?;
+        self.min_stake = min_stake;
+        self.max_stake = max_stake;
+
+        self.pending_config.battle_price = None;
+        if self.pending_config.fee_schedule.is_none() && self.pending_config_delay_seconds.is_none()
+        {
+            self.pending_config = PendingConfig::default();
+        }
+
+        self.validate_stake(self.battle_price)
+    }
+
+    /// Checks a stake in base units against the admin's stake bounds, the battle
+    /// price is the default stake so it has to fall within them too
+    pub fn validate_stake(&self, stake: u64) -> Result<()> {
+        self.mint_config().validate_stake(stake)
     }
 }
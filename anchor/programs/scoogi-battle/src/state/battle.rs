@@ -1,10 +1,22 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants, FeeSchedule, MintConfig, RatingBand, ScoogiBattleError};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub enum BattleStatus {
     Pending,
     InProgress,
     Completed,
+    Disputed,
+    ResultRecorded,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum SettlementMode {
+    /// the result authority reports the winner
+    Oracle,
+    /// both players attest the winner and the results must agree
+    DualConfirmation,
 }
 
 #[account]
@@ -14,10 +26,221 @@ pub struct Battle {
     pub player_two: Pubkey,
     pub winner: Pubkey,
     pub battle_status: BattleStatus,
+    pub settlement_mode: SettlementMode,
+    /// terms snapshotted from the admin account or mint config when the battle is created,
+    /// `mint` is `constants::SOL_MINT` and the treasury a wallet for SOL battles
+    pub mint: Pubkey,
+    pub stake_amount: u64,
+    pub fee_schedule: FeeSchedule,
+    pub treasury_token_account: Pubkey,
+    pub player_one_attestation: Option<u8>,
+    pub player_two_attestation: Option<u8>,
+    pub dispute_deadline: i64,
+    pub created_at: i64,
+    pub joined_at: i64,
+    /// `Pubkey::default()` when the player was not referred
+    pub player_one_referrer: Pubkey,
+    pub player_two_referrer: Pubkey,
+    /// layout version, `migrate_battle` brings older accounts up to `constants::BATTLE_VERSION`
+    pub version: u8,
+    /// what each player paid in, refunded as is by an admin withdrawal
+    pub player_one_deposit: u64,
+    pub player_two_deposit: u64,
+    /// `None` when anyone can join, carved out of `reserved`
+    pub rating_band: Option<RatingBand>,
+    /// the only player who can join, `Pubkey::default()` when anyone can. Grew the
+    /// account past its reserved space in version 3.
+    pub invited_opponent: Pubkey,
+    /// room for new fields without growing the account
+    pub reserved: [u8; 7],
 }
 
 impl Battle {
     pub fn size() -> usize {
-        8 + 8 + 32 + 32 + 32 + 1
+        8 + 8
+            + 32
+            + 32
+            + 32
+            + 1
+            + 1
+            + 32
+            + 8
+            + FeeSchedule::size()
+            + 32
+            + 2
+            + 2
+            + 8
+            + 8
+            + 8
+            + 32
+            + 32
+            + 1
+            + 8
+            + 8
+            + (1 + RatingBand::size())
+            + 32
+            + 7
+    }
+
+    /// Opens a new battle hosted by `player_one` on the terms of `mint_config`
+    pub fn open(
+        &mut self,
+        battle_id: u64,
+        player_one: Pubkey,
+        settlement_mode: SettlementMode,
+        mint_config: &MintConfig,
+        stake_amount: u64,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        let referrer = referrer.unwrap_or_default();
+        if referrer == player_one {
+            return Err(ScoogiBattleError::InvalidReferrer.into());
+        }
+
+        // a battle snapshotting no treasury could never pay out
+        if mint_config.treasury_token_account == Pubkey::default() {
+            return Err(ScoogiBattleError::MissingTreasury.into());
+        }
+
+        self.battle_id = battle_id;
+        self.player_one = player_one;
+        self.player_two = Pubkey::default();
+        self.battle_status = BattleStatus::Pending;
+        self.settlement_mode = settlement_mode;
+        self.mint = mint_config.mint;
+        self.stake_amount = stake_amount;
+        self.fee_schedule = mint_config.fee_schedule;
+        self.treasury_token_account = mint_config.treasury_token_account;
+        self.player_one_attestation = None;
+        self.player_two_attestation = None;
+        self.created_at = Clock::get()?.unix_timestamp;
+        self.player_one_referrer = referrer;
+        self.player_one_deposit = stake_amount;
+        self.player_two_deposit = 0;
+        self.version = constants::BATTLE_VERSION;
+
+        Ok(())
+    }
+
+    /// Limits the battle to challengers rated within `rating_band`
+    pub fn set_rating_band(&mut self, rating_band: Option<RatingBand>) -> Result<()> {
+        if let Some(rating_band) = &rating_band {
+            rating_band.validate()?;
+        }
+
+        self.rating_band = rating_band;
+
+        Ok(())
+    }
+
+    /// Reserves the battle for `invited_opponent`, open to anyone when `None`
+    pub fn invite(&mut self, invited_opponent: Option<Pubkey>) -> Result<()> {
+        let invited_opponent = invited_opponent.unwrap_or_default();
+        if invited_opponent == self.player_one {
+            return Err(ScoogiBattleError::InvalidOpponent.into());
+        }
+
+        self.invited_opponent = invited_opponent;
+
+        Ok(())
+    }
+
+    /// Checks a challenger is the invited opponent, if the battle has one
+    pub fn require_invited(&self, challenger: &Pubkey) -> Result<()> {
+        if self.invited_opponent != Pubkey::default() && self.invited_opponent != *challenger {
+            return Err(ScoogiBattleError::NotInvited.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks a challenger's rating against the battle's rating band, if it has one
+    pub fn require_rating_in_band(&self, rating: u32) -> Result<()> {
+        match &self.rating_band {
+            Some(rating_band) if !rating_band.contains(rating) => {
+                Err(ScoogiBattleError::RatingOutOfBand.into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// What each player paid in. Battles opened before version 2 never recorded their
+    /// deposits and read them as zero, so they fall back to each joined player's stake.
+    pub fn deposits(&self) -> (u64, u64) {
+        let deposit = |deposit: u64| {
+            if self.version < 2 || deposit == 0 {
+                self.stake_amount
+            } else {
+                deposit
+            }
+        };
+
+        let player_two_deposit = if self.player_two == Pubkey::default() {
+            0
+        } else {
+            deposit(self.player_two_deposit)
+        };
+
+        (deposit(self.player_one_deposit), player_two_deposit)
+    }
+
+    /// Splits an admin withdrawal of `pot` into each player's deposit and whatever is
+    /// left over for the treasury. A pot short of the deposits, such as after a token
+    /// transfer fee, is refunded in proportion to them instead.
+    pub fn withdrawal_refunds(&self, pot: u64) -> Result<(u64, u64, u64)> {
+        let (player_one_deposit, player_two_deposit) = self.deposits();
+        let deposits = player_one_deposit as u128 + player_two_deposit as u128;
+        if deposits == 0 {
+            return Err(ScoogiBattleError::InvalidWithdrawal.into());
+        }
+
+        if pot as u128 >= deposits {
+            let swept = pot - player_one_deposit - player_two_deposit;
+            return Ok((player_one_deposit, player_two_deposit, swept));
+        }
+
+        let refund = |deposit: u64| (pot as u128 * deposit as u128 / deposits) as u64;
+        let player_one_refund = refund(player_one_deposit);
+        let player_two_refund = refund(player_two_deposit);
+        let swept = pot - player_one_refund - player_two_refund;
+
+        Ok((player_one_refund, player_two_refund, swept))
+    }
+
+    /// Maps a battle result (0 = player one, 1 = player two) to the winning player
+    pub fn winner_for(&self, battle_result: u8) -> Result<Pubkey> {
+        match battle_result {
+            0 => Ok(self.player_one),
+            1 => Ok(self.player_two),
+            _ => Err(ScoogiBattleError::InvalidBattleResult.into()),
+        }
+    }
+
+    /// When an open or in progress battle can be expired, `None` if timeouts are disabled
+    pub fn expires_at(&self, battle_timeout_seconds: i64) -> Option<i64> {
+        if battle_timeout_seconds <= 0 {
+            return None;
+        }
+
+        match self.battle_status {
+            BattleStatus::Pending => self.created_at.checked_add(battle_timeout_seconds),
+            BattleStatus::InProgress => self.joined_at.checked_add(battle_timeout_seconds),
+            _ => None,
+        }
+    }
+
+    /// The message the result authority signs to settle a battle off-chain:
+    /// `program_id || battle_id (le) || created_at (le) || player_one || player_two || winner`.
+    /// Battle accounts are reused once closed, `created_at` keeps a result signed for
+    /// an earlier battle under the same id from settling a later one.
+    pub fn result_message(&self, program_id: &Pubkey) -> Vec<u8> {
+        let mut message = Vec::with_capacity(32 + 8 + 8 + 32 * 3);
+        message.extend_from_slice(program_id.as_ref());
+        message.extend_from_slice(&self.battle_id.to_le_bytes());
+        message.extend_from_slice(&self.created_at.to_le_bytes());
+        message.extend_from_slice(self.player_one.as_ref());
+        message.extend_from_slice(self.player_two.as_ref());
+        message.extend_from_slice(self.winner.as_ref());
+        message
     }
 }
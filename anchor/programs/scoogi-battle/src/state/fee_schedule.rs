@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::ScoogiBattleError;
+
+pub const MAX_BPS: u64 = 10_000;
+
+/// How a settled pot is split, `referrer_amount` is paid to each referrer
+pub struct Payout {
+    pub winner_amount: u64,
+    pub burn_amount: u64,
+    pub treasury_amount: u64,
+    pub referrer_amount: u64,
+}
+
+/// Fees taken from a settled pot, in basis points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeSchedule {
+    /// burned from the pot
+    pub burn_fee_bps: u64,
+    /// sent to the treasury token account
+    pub treasury_fee_bps: u64,
+    /// paid to each player's referrer out of that player's stake
+    pub referrer_fee_bps: u64,
+}
+
+impl FeeSchedule {
+    pub fn size() -> usize {
+        8 + 8 + 8
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let total_bps = self
+            .burn_fee_bps
+            .checked_add(self.treasury_fee_bps)
+            .and_then(|bps| bps.checked_add(self.referrer_fee_bps))
+            .ok_or(ScoogiBattleError::FeeOutOfRange)?;
+
+        if total_bps > MAX_BPS {
+            return Err(ScoogiBattleError::FeeOutOfRange.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(amount: u64, bps: u64) -> u64 {
+        (amount as u128)
+            .checked_mul(bps as u128)
+            .unwrap()
+            .checked_div(MAX_BPS as u128)
+            .unwrap() as u64
+    }
+
+    /// Splits `pot` for a battle with `referrers` referred players, each player's
+    /// referrer earns on that player's half of the pot
+    pub fn split(&self, pot: u64, referrers: usize) -> Result<Payout> {
+        let burn_amount = FeeSchedule::apply(pot, self.burn_fee_bps);
+        let treasury_amount = FeeSchedule::apply(pot, self.treasury_fee_bps);
+        let referrer_amount =
+            FeeSchedule::apply(pot.checked_div(2).unwrap(), self.referrer_fee_bps);
+
+        let winner_amount = pot
+            .checked_sub(burn_amount)
+            .and_then(|amount| amount.checked_sub(treasury_amount))
+            .and_then(|amount| {
+                amount.checked_sub(referrer_amount.checked_mul(referrers as u64).unwrap())
+            })
+            .ok_or(ScoogiBattleError::FeeOutOfRange)?;
+
+        Ok(Payout {
+            winner_amount,
+            burn_amount,
+            treasury_amount,
+            referrer_amount,
+        })
+    }
+}
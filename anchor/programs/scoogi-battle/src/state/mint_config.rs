@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{Admin, FeeSchedule, ScoogiBattleError};
+
+/// Terms for wagering a whitelisted mint other than the admin's default mint,
+/// or SOL when `mint` is `constants::SOL_MINT`
+#[account]
+pub struct MintConfig {
+    pub mint: Pubkey,
+    pub battle_price: u64,
+    pub min_stake: u64,
+    pub max_stake: u64,
+    pub fee_schedule: FeeSchedule,
+    /// the treasury wallet for SOL
+    pub treasury_token_account: Pubkey,
+}
+
+impl MintConfig {
+    pub fn size() -> usize {
+        8 + 32 + 8 + 8 + 8 + FeeSchedule::size() + 32
+    }
+
+    /// The stake of a battle created with `stake`, both in base units,
+    /// the battle price when no stake was chosen
+    pub fn stake_amount(&self, stake: Option<u64>) -> Result<u64> {
+        let stake_amount = match stake {
+            Some(0) => return Err(ScoogiBattleError::PriceZero.into()),
+            Some(stake) => stake,
+            None => self.battle_price,
+        };
+        self.validate_stake(stake_amount)?;
+
+        Ok(stake_amount)
+    }
+
+    pub fn validate_stake(&self, stake: u64) -> Result<()> {
+        if stake < self.min_stake || stake > self.max_stake {
+            return Err(ScoogiBattleError::StakeOutOfRange.into());
+        }
+
+        Ok(())
+    }
+
+    /// Sets the terms from amounts in whole tokens of a mint with `decimals`,
+    /// the battle price is the default stake so it has to fall within the bounds
+    pub fn set_terms(
+        &mut self,
+        decimals: u8,
+        battle_price: u64,
+        min_stake: u64,
+        max_stake: u64,
+        fee_schedule: FeeSchedule,
+    ) -> Result<()> {
+        fee_schedule.validate()?;
+
+        let min_stake = Admin::bound_to_base_units(min_stake, decimals)?;
+        let max_stake = Admin::bound_to_base_units(max_stake, decimals)?;
+
+        if min_stake > max_stake {
+            return Err(ScoogiBattleError::StakeOutOfRange.into());
+        }
+
+        self.battle_price = Admin::to_base_units(battle_price, decimals)?;
+        self.min_stake = min_stake;
+        self.max_stake = max_stake;
+        self.fee_schedule = fee_schedule;
+
+        self.validate_stake(self.battle_price)
+    }
+}
@@ -1,5 +1,19 @@
 mod admin;
 mod battle;
+mod fee_schedule;
+mod mint_config;
+mod pending_config;
+mod pending_mint_config;
+mod player_profile;
+mod rating_band;
+mod sol_vault;
 
 pub use admin::*;
 pub use battle::*;
+pub use fee_schedule::*;
+pub use mint_config::*;
+pub use pending_config::*;
+pub use pending_mint_config::*;
+pub use player_profile::*;
+pub use rating_band::*;
+pub use sol_vault::*;
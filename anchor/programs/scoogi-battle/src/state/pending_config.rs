@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::FeeSchedule;
+
+/// A config change queued by the config manager, applied by `apply_config`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PendingConfig {
+    pub battle_price: Option<u64>,
+    pub fee_schedule: Option<FeeSchedule>,
+    /// when the change can be applied, 0 when nothing is queued
+    pub effective_at: i64,
+}
+
+impl PendingConfig {
+    pub fn size() -> usize {
+        (1 + 8) + (1 + FeeSchedule::size()) + 8
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{FeeSchedule, MintConfig};
+
+/// A mint config change queued by the config manager behind the config delay,
+/// applied by `apply_mint_config`. Terms are in base units, converted when queued.
+#[account]
+pub struct PendingMintConfig {
+    pub mint: Pubkey,
+    pub battle_price: u64,
+    pub min_stake: u64,
+    pub max_stake: u64,
+    pub fee_schedule: FeeSchedule,
+    pub treasury_token_account: Pubkey,
+    /// closes the mint config instead of setting its terms
+    pub remove: bool,
+    /// when the change can be applied
+    pub effective_at: i64,
+}
+
+impl PendingMintConfig {
+    pub fn size() -> usize {
+        8 + 32 + 8 + 8 + 8 + FeeSchedule::size() + 32 + 1 + 8
+    }
+
+    /// Queues `terms` for their mint, replacing and restarting anything queued before
+    pub fn queue(&mut self, terms: &MintConfig, effective_at: i64) {
+        self.mint = terms.mint;
+        self.battle_price = terms.battle_price;
+        self.min_stake = terms.min_stake;
+        self.max_stake = terms.max_stake;
+        self.fee_schedule = terms.fee_schedule;
+        self.treasury_token_account = terms.treasury_token_account;
+        self.remove = false;
+        self.effective_at = effective_at;
+    }
+
+    /// Queues closing the mint config of `mint`
+    pub fn queue_removal(&mut self, mint: Pubkey, effective_at: i64) {
+        self.mint = mint;
+        self.remove = true;
+        self.effective_at = effective_at;
+    }
+
+    /// Sets the queued terms on the mint config
+    pub fn apply(&self, mint_config: &mut MintConfig) {
+        mint_config.mint = self.mint;
+        mint_config.battle_price = self.battle_price;
+        mint_config.min_stake = self.min_stake;
+        mint_config.max_stake = self.max_stake;
+        mint_config.fee_schedule = self.fee_schedule;
+        mint_config.treasury_token_account = self.treasury_token_account;
+    }
+}
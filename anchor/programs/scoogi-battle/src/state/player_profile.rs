@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants;
+
+/// A player's record across settled battles: `wins` and `losses` count battles
+/// and `rating` is an Elo rating. Amounts are not kept since stakes in different
+/// mints don't add up, `BattleSettled` has them in base units of the battle's mint.
+#[account]
+pub struct PlayerProfile {
+    pub player: Pubkey,
+    pub wins: u64,
+    pub losses: u64,
+    /// held `total_wagered` and `total_won` before version 2, which summed base
+    /// units across mints, `migrate_player_profile` zeroes it
+    pub retired: [u8; 16],
+    pub rating: u32,
+    /// layout version, `migrate_player_profile` brings older accounts up to
+    /// `constants::PLAYER_PROFILE_VERSION`
+    pub version: u8,
+    /// room for new fields without growing the account
+    pub reserved: [u8; 64],
+}
+
+impl PlayerProfile {
+    pub fn size() -> usize {
+        8 + 32 + 8 + 8 + 16 + 4 + 1 + 64
+    }
+
+    /// Sets up a profile `init_if_needed` just created, leaves existing ones be
+    pub fn open(&mut self, player: Pubkey) {
+        if self.player == Pubkey::default() {
+            self.player = player;
+            self.rating = constants::DEFAULT_RATING;
+            self.version = constants::PLAYER_PROFILE_VERSION;
+        }
+    }
+
+    /// Records a settled battle the player won or lost
+    pub fn record(&mut self, won: bool) {
+        if won {
+            self.wins = self.wins.saturating_add(1);
+        } else {
+            self.losses = self.losses.saturating_add(1);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::ScoogiBattleError;
+
+/// The Elo ratings a battle's host accepts challengers from, both inclusive
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct RatingBand {
+    pub min_rating: u32,
+    pub max_rating: u32,
+}
+
+impl RatingBand {
+    pub fn size() -> usize {
+        4 + 4
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.min_rating > self.max_rating {
+            return Err(ScoogiBattleError::InvalidRatingBand.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn contains(&self, rating: u32) -> bool {
+        (self.min_rating..=self.max_rating).contains(&rating)
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// Holds a SOL battle's stakes as lamports, in place of a battle token account
+#[account]
+pub struct SolVault {}
+
+impl SolVault {
+    pub fn size() -> usize {
+        8
+    }
+}
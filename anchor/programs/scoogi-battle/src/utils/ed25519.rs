@@ -0,0 +1,72 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+
+use crate::ScoogiBattleError;
+
+// Ed25519 program instruction data: a signature count, a padding byte and then
+// one 14 byte offsets struct per signature.
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+
+/// Checks that the instruction right before the current one is an Ed25519
+/// program instruction verifying `message` signed by `signer`.
+pub fn verify_ed25519_ix(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions)?;
+    if current_index == 0 {
+        return Err(ScoogiBattleError::InvalidResultSignature.into());
+    }
+
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions)?;
+    if ix.program_id != ed25519_program::ID || !ix.accounts.is_empty() {
+        return Err(ScoogiBattleError::InvalidResultSignature.into());
+    }
+
+    let data = &ix.data;
+    if data.len() < SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE || data[0] != 1 {
+        return Err(ScoogiBattleError::InvalidResultSignature.into());
+    }
+
+    let read_u16 = |index: usize| -> usize {
+        let start = SIGNATURE_OFFSETS_START + index * 2;
+        u16::from_le_bytes([data[start], data[start + 1]]) as usize
+    };
+
+    let signature_ix_index = read_u16(1);
+    let public_key_offset = read_u16(2);
+    let public_key_ix_index = read_u16(3);
+    let message_offset = read_u16(4);
+    let message_size = read_u16(5);
+    let message_ix_index = read_u16(6);
+
+    // the signature, key and message must all live in the Ed25519 instruction itself
+    let this_ix = u16::MAX as usize;
+    if signature_ix_index != this_ix
+        || public_key_ix_index != this_ix
+        || message_ix_index != this_ix
+    {
+        return Err(ScoogiBattleError::InvalidResultSignature.into());
+    }
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + PUBKEY_SIZE)
+        .ok_or(ScoogiBattleError::InvalidResultSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ScoogiBattleError::InvalidResultSignature)?;
+
+    if public_key != signer.as_ref() || signed_message != message {
+        return Err(ScoogiBattleError::InvalidResultSignature.into());
+    }
+
+    Ok(())
+}
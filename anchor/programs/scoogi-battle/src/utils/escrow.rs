@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022 as token,
+    token_interface::{Burn, CloseAccount, TransferChecked},
+};
+
+use crate::{Battle, FeeSchedule, Payout, ScoogiBattleError};
+
+/// The accounts of the referrers a settlement has to pay, token accounts or
+/// wallets for SOL battles, erroring when a battle records a referrer whose
+/// account was not passed in
+pub fn referrer_accounts<'info>(
+    battle: &Battle,
+    player_one_referrer_account: Option<AccountInfo<'info>>,
+    player_two_referrer_account: Option<AccountInfo<'info>>,
+) -> Result<Vec<AccountInfo<'info>>> {
+    let mut referrer_accounts = Vec::with_capacity(2);
+
+    for (referrer, referrer_account) in [
+        (battle.player_one_referrer, player_one_referrer_account),
+        (battle.player_two_referrer, player_two_referrer_account),
+    ] {
+        if referrer == Pubkey::default() {
+            continue;
+        }
+
+        referrer_accounts.push(referrer_account.ok_or(ScoogiBattleError::MissingReferrerAccount)?);
+    }
+
+    Ok(referrer_accounts)
+}
+
+/// A battle token account together with the seeds it signs with, so every
+/// instruction moving a pot does it the same way.
+pub struct BattleEscrow<'a, 'info> {
+    pub token_program: AccountInfo<'info>,
+    pub battle_token_account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub decimals: u8,
+    pub signer_seeds: &'a [&'a [&'a [u8]]],
+}
+
+impl<'a, 'info> BattleEscrow<'a, 'info> {
+    pub fn transfer(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            from: self.battle_token_account.clone(),
+            mint: self.mint.clone(),
+            to: to.clone(),
+            authority: self.battle_token_account.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.clone(),
+            cpi_accounts,
+            self.signer_seeds,
+        );
+
+        token::transfer_checked(cpi_ctx, amount, self.decimals)
+    }
+
+    pub fn close(&self, destination: &AccountInfo<'info>) -> Result<()> {
+        let cpi_accounts = CloseAccount {
+            account: self.battle_token_account.clone(),
+            destination: destination.clone(),
+            authority: self.battle_token_account.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.clone(),
+            cpi_accounts,
+            self.signer_seeds,
+        );
+
+        token::close_account(cpi_ctx)
+    }
+
+    pub fn burn(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = Burn {
+            mint: self.mint.clone(),
+            from: self.battle_token_account.clone(),
+            authority: self.battle_token_account.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.clone(),
+            cpi_accounts,
+            self.signer_seeds,
+        );
+
+        token::burn(cpi_ctx, amount)
+    }
+
+    /// Pays `pot` out to the winner after taking the burn, treasury and referrer fees,
+    /// each player's referrer earns on that player's half of the pot
+    pub fn pay_out(
+        &self,
+        pot: u64,
+        fee_schedule: &FeeSchedule,
+        winner_token_account: &AccountInfo<'info>,
+        treasury_token_account: &AccountInfo<'info>,
+        referrer_token_accounts: &[AccountInfo<'info>],
+    ) -> Result<Payout> {
+        let payout = fee_schedule.split(pot, referrer_token_accounts.len())?;
+
+        self.transfer(winner_token_account, payout.winner_amount)?;
+        self.transfer(treasury_token_account, payout.treasury_amount)?;
+        for referrer_token_account in referrer_token_accounts {
+            self.transfer(referrer_token_account, payout.referrer_amount)?;
+        }
+        self.burn(payout.burn_amount)?;
+
+        Ok(payout)
+    }
+
+    /// Splits `pot` evenly between both players, player one takes any odd unit
+    pub fn refund(
+        &self,
+        pot: u64,
+        player_one_token_account: &AccountInfo<'info>,
+        player_two_token_account: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let player_two_amount = pot.checked_div(2).unwrap();
+        let player_one_amount = pot.checked_sub(player_two_amount).unwrap();
+
+        self.transfer(player_one_token_account, player_one_amount)?;
+        self.transfer(player_two_token_account, player_two_amount)
+    }
+}
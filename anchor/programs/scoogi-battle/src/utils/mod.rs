@@ -0,0 +1,11 @@
+mod ed25519;
+mod escrow;
+mod rating;
+mod settlement;
+mod sol_escrow;
+
+pub use ed25519::*;
+pub use escrow::*;
+pub use rating::*;
+pub use settlement::*;
+pub use sol_escrow::*;
@@ -0,0 +1,44 @@
+use crate::constants;
+
+/// Fixed-point scale of the odds and expected scores below
+const SCORE_SCALE: u128 = 1_000_000_000_000;
+
+/// 10^(1/400) at `SCORE_SCALE`, the odds one rating point is worth
+const ODDS_PER_POINT: u128 = 1_005_773_063_002;
+
+/// Gaps wider than this count as this, like FIDE's 400 point rule
+const MAX_RATING_GAP: u32 = 400;
+
+/// 10^(gap/400) at `SCORE_SCALE`, by squaring so it stays deterministic
+fn odds(gap: u32) -> u128 {
+    let mut result = SCORE_SCALE;
+    let mut base = ODDS_PER_POINT;
+    let mut exponent = gap.min(MAX_RATING_GAP);
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base / SCORE_SCALE;
+        }
+        base = base * base / SCORE_SCALE;
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The winner's expected score against the loser at `SCORE_SCALE`
+fn expected_score(winner_rating: u32, loser_rating: u32) -> u128 {
+    if loser_rating >= winner_rating {
+        SCORE_SCALE * SCORE_SCALE / (SCORE_SCALE + odds(loser_rating - winner_rating))
+    } else {
+        SCORE_SCALE - SCORE_SCALE * SCORE_SCALE / (SCORE_SCALE + odds(winner_rating - loser_rating))
+    }
+}
+
+/// The Elo points the winner takes from the loser, rounded to the nearest point
+pub fn rating_change(winner_rating: u32, loser_rating: u32) -> u32 {
+    let surprise = SCORE_SCALE - expected_score(winner_rating, loser_rating);
+    let change = (constants::RATING_K_FACTOR as u128 * surprise + SCORE_SCALE / 2) / SCORE_SCALE;
+
+    change as u32
+}
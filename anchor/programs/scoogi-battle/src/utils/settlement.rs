@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    utils, Battle, BattleStatus, PlayerProfile, RatingsUpdated, ScoogiBattleError, SettlementMode,
+};
+
+/// Checks a reported result against the battle's settlement mode and records the
+/// winner, returning `true` when the pot can be paid out right away and `false`
+/// when it waits on the dispute window
+pub fn record_result(
+    battle: &mut Battle,
+    battle_result: u8,
+    winner: &Pubkey,
+    result_authority: &AccountInfo,
+    instructions_sysvar: &AccountInfo,
+    program_id: &Pubkey,
+    dispute_window_seconds: i64,
+) -> Result<bool> {
+    match battle.battle_status {
+        BattleStatus::InProgress => {
+            battle.battle_status = BattleStatus::Completed;
+            battle.winner = battle.winner_for(battle_result)?;
+
+            if *winner != battle.winner {
+                return Err(ScoogiBattleError::InvalidWinner.into());
+            }
+
+            match battle.settlement_mode {
+                // the winner is taken from the result signed by the result authority,
+                // not from whoever submits the transaction
+                SettlementMode::Oracle => {
+                    // a result authority that did not sign the transaction itself must have
+                    // signed the result off-chain, letting anyone relay the settlement
+                    if !result_authority.is_signer {
+                        utils::verify_ed25519_ix(
+                            instructions_sysvar,
+                            &result_authority.key(),
+                            &battle.result_message(program_id),
+                        )?;
+                    }
+                }
+                // both players must have attested this exact result
+                SettlementMode::DualConfirmation => {
+                    if battle.player_one_attestation != Some(battle_result)
+                        || battle.player_two_attestation != Some(battle_result)
+                    {
+                        return Err(ScoogiBattleError::MissingAttestation.into());
+                    }
+                }
+            }
+
+            // give the loser a chance to dispute an oracle result before paying out,
+            // players who both attested the result have nothing left to dispute
+            if dispute_window_seconds > 0 && battle.settlement_mode == SettlementMode::Oracle {
+                battle.battle_status = BattleStatus::ResultRecorded;
+                battle.dispute_deadline = Clock::get()?
+                    .unix_timestamp
+                    .checked_add(dispute_window_seconds)
+                    .ok_or(ScoogiBattleError::InvalidDisputeWindow)?;
+
+                return Ok(false);
+            }
+
+            Ok(true)
+        }
+        _ => Err(ScoogiBattleError::InvalidBattleStatus.into()),
+    }
+}
+
+/// Records a battle paid out to its winner on both players' profiles and moves
+/// their ratings, returning the new ratings to emit. Battles opened before
+/// profiles existed settle into profiles created just now.
+pub fn record_profiles(
+    battle: &Battle,
+    player_one_profile: &mut PlayerProfile,
+    player_two_profile: &mut PlayerProfile,
+) -> RatingsUpdated {
+    player_one_profile.open(battle.player_one);
+    player_two_profile.open(battle.player_two);
+
+    let player_one_won = battle.winner == battle.player_one;
+
+    player_one_profile.record(player_one_won);
+    player_two_profile.record(!player_one_won);
+
+    let (winner, loser) = if player_one_won {
+        (player_one_profile, player_two_profile)
+    } else {
+        (player_two_profile, player_one_profile)
+    };
+
+    let rating_change = utils::rating_change(winner.rating, loser.rating);
+    winner.rating = winner.rating.saturating_add(rating_change);
+    loser.rating = loser.rating.saturating_sub(rating_change);
+
+    RatingsUpdated {
+        battle_id: battle.battle_id,
+        winner: winner.player,
+        winner_rating: winner.rating,
+        loser: loser.player,
+        loser_rating: loser.rating,
+        rating_change,
+    }
+}
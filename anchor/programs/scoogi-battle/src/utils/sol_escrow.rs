@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{FeeSchedule, Payout};
+
+/// A battle's SOL vault, owned by the program so lamports are moved out of it
+/// directly instead of through the system program
+pub struct SolEscrow<'info> {
+    pub sol_vault: AccountInfo<'info>,
+}
+
+impl<'info> SolEscrow<'info> {
+    /// The staked lamports, everything in the vault above its rent exemption
+    pub fn pot(&self) -> Result<u64> {
+        let rent = Rent::get()?.minimum_balance(self.sol_vault.data_len());
+
+        Ok(self.sol_vault.lamports().saturating_sub(rent))
+    }
+
+    pub fn transfer(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        self.sol_vault.sub_lamports(amount)?;
+        to.add_lamports(amount)?;
+
+        Ok(())
+    }
+
+    /// Whether `to` is left rent exempt after receiving `amount`, the runtime fails a
+    /// transaction that funds a wallet with less than its rent exemption
+    pub fn can_receive(to: &AccountInfo<'info>, amount: u64) -> Result<bool> {
+        let rent = Rent::get()?.minimum_balance(to.data_len());
+
+        Ok(amount == 0 || to.lamports().saturating_add(amount) >= rent)
+    }
+
+    /// Pays `pot` out to the winner after taking the burn, treasury and referrer fees,
+    /// burned lamports go to the incinerator which the runtime empties every slot.
+    /// A fee share its wallet could not hold rent exempt goes to the winner instead,
+    /// so an empty treasury or referrer wallet cannot block the payout.
+    pub fn pay_out(
+        &self,
+        pot: u64,
+        fee_schedule: &FeeSchedule,
+        winner: &AccountInfo<'info>,
+        treasury: &AccountInfo<'info>,
+        referrers: &[AccountInfo<'info>],
+        incinerator: &AccountInfo<'info>,
+    ) -> Result<Payout> {
+        // each referrer's share is the same however many are paid
+        let referrer_amount = fee_schedule.split(pot, referrers.len())?.referrer_amount;
+        let mut paid_referrers = Vec::with_capacity(referrers.len());
+        for referrer in referrers {
+            if SolEscrow::can_receive(referrer, referrer_amount)? {
+                paid_referrers.push(referrer);
+            }
+        }
+
+        let mut payout = fee_schedule.split(pot, paid_referrers.len())?;
+        if !SolEscrow::can_receive(treasury, payout.treasury_amount)? {
+            payout.winner_amount = payout
+                .winner_amount
+                .checked_add(payout.treasury_amount)
+                .unwrap();
+            payout.treasury_amount = 0;
+        }
+
+        self.transfer(winner, payout.winner_amount)?;
+        self.transfer(treasury, payout.treasury_amount)?;
+        for referrer in paid_referrers {
+            self.transfer(referrer, payout.referrer_amount)?;
+        }
+        self.transfer(incinerator, payout.burn_amount)?;
+
+        Ok(payout)
+    }
+
+    /// Splits `pot` evenly between both players, player one takes any odd lamport
+    pub fn refund(
+        &self,
+        pot: u64,
+        player_one: &AccountInfo<'info>,
+        player_two: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let player_two_amount = pot.checked_div(2).unwrap();
+        let player_one_amount = pot.checked_sub(player_two_amount).unwrap();
+
+        self.transfer(player_one, player_one_amount)?;
+        self.transfer(player_two, player_two_amount)
+    }
+}
@@ -1,8 +1,57 @@
 // Here we export some useful types and functions for interacting with the Anchor program.
-import { Program } from '@coral-xyz/anchor';
+import { BN, Program, utils } from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
 import type { ScoogiBattle } from '../target/types/scoogi_battle';
 import { IDL as ScoogiBattleIDL } from '../target/types/scoogi_battle';
 
 // Re-export the generated IDL and type
 export { ScoogiBattle, ScoogiBattleIDL };
 export type ScoogiBattleProgram = Program<ScoogiBattle>;
+
+// The message the result authority signs with Ed25519 so anyone can relay a
+// `recordBattleResult` settlement on its behalf. `createdAt` is the battle
+// account's, so the signature cannot settle a later battle reusing the id.
+export function getBattleResultMessage(
+  programId: PublicKey,
+  battleId: BN,
+  createdAt: BN,
+  playerOne: PublicKey,
+  playerTwo: PublicKey,
+  winner: PublicKey
+): Buffer {
+  return Buffer.concat([
+    programId.toBuffer(),
+    battleId.toArrayLike(Buffer, 'le', 8),
+    createdAt.toTwos(64).toArrayLike(Buffer, 'le', 8),
+    playerOne.toBuffer(),
+    playerTwo.toBuffer(),
+    winner.toBuffer(),
+  ]);
+}
+
+// Byte offsets into a `Battle` account, for `getProgramAccounts` filters.
+export const BATTLE_STATUS_OFFSET = 8 + 8 + 32 * 3;
+export const BATTLE_STAKE_AMOUNT_OFFSET = BATTLE_STATUS_OFFSET + 1 + 1 + 32;
+
+// Open (pending) battles whose stake is exactly `stakeAmount` base units.
+export async function getOpenBattlesByStake(
+  program: ScoogiBattleProgram,
+  stakeAmount: BN
+) {
+  return program.account.battle.all([
+    {
+      memcmp: {
+        offset: BATTLE_STATUS_OFFSET,
+        bytes: utils.bytes.bs58.encode([0]),
+      },
+    },
+    {
+      memcmp: {
+        offset: BATTLE_STAKE_AMOUNT_OFFSET,
+        bytes: utils.bytes.bs58.encode(
+          stakeAmount.toArrayLike(Buffer, 'le', 8)
+        ),
+      },
+    },
+  ]);
+}
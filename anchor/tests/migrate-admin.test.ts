@@ -0,0 +1,134 @@
+import * as anchor from '@coral-xyz/anchor';
+
+import {
+  AccountLayout,
+  AccountState,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import { BankrunProvider, startAnchor } from 'anchor-bankrun';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from '@solana/web3.js';
+
+import { Program } from '@coral-xyz/anchor';
+import { IDL, ScoogiBattle } from '../target/types/scoogi_battle';
+import path from 'path';
+
+const PROGRAM_ID = new PublicKey(
+  'GM9mV2Km6iwaQsVjs5x4wb4CL2XBemS3cgZ28sCydiBR'
+);
+const ADMIN_SEED = Buffer.from('admin');
+
+// The admin PDA is a singleton the main suite initializes, so migrating an
+// account written by an older program runs against its own bank instead.
+describe('migrate_admin', () => {
+  it('grows an admin account from the first layout', async () => {
+    const admin = Keypair.generate();
+    const mint = Keypair.generate().publicKey;
+    const treasuryTokenAccount = Keypair.generate().publicKey;
+    const resultAuthority = Keypair.generate().publicKey;
+    const [adminAccount] = PublicKey.findProgramAddressSync(
+      [ADMIN_SEED],
+      PROGRAM_ID
+    );
+
+    // the first layout: admin, mint, burn_fee_bps, battle_price
+    const legacyData = Buffer.concat([
+      anchor.BorshAccountsCoder.accountDiscriminator('Admin'),
+      admin.publicKey.toBuffer(),
+      mint.toBuffer(),
+      new anchor.BN(100).toArrayLike(Buffer, 'le', 8),
+      new anchor.BN(10_000).toArrayLike(Buffer, 'le', 8),
+    ]);
+
+    const context = await startAnchor(
+      path.resolve(__dirname, '..'),
+      [],
+      [
+        {
+          address: admin.publicKey,
+          info: {
+            lamports: 10 * LAMPORTS_PER_SOL,
+            data: Buffer.alloc(0),
+            owner: SystemProgram.programId,
+            executable: false,
+          },
+        },
+      ]
+    );
+
+    // only rent exempt at its old size, so the admin tops up the rent
+    const rent = await context.banksClient.getRent();
+    context.setAccount(adminAccount, {
+      lamports: Number(rent.minimumBalance(BigInt(legacyData.length))),
+      data: legacyData,
+      owner: PROGRAM_ID,
+      executable: false,
+    });
+
+    // the first layout had no treasury, so the migration takes one in the mint
+    const treasuryData = Buffer.alloc(AccountLayout.span);
+    AccountLayout.encode(
+      {
+        mint,
+        owner: admin.publicKey,
+        amount: BigInt(0),
+        delegateOption: 0,
+        delegate: PublicKey.default,
+        state: AccountState.Initialized,
+        isNativeOption: 0,
+        isNative: BigInt(0),
+        delegatedAmount: BigInt(0),
+        closeAuthorityOption: 0,
+        closeAuthority: PublicKey.default,
+      },
+      treasuryData
+    );
+    context.setAccount(treasuryTokenAccount, {
+      lamports: Number(rent.minimumBalance(BigInt(treasuryData.length))),
+      data: treasuryData,
+      owner: TOKEN_PROGRAM_ID,
+      executable: false,
+    });
+
+    const provider = new BankrunProvider(context);
+    const program = new Program<ScoogiBattle>(IDL, PROGRAM_ID, provider);
+
+    await program.methods
+      .migrateAdmin()
+      .accounts({
+        admin: admin.publicKey,
+        adminAccount,
+        treasuryTokenAccount,
+        resultAuthority,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const accountInfo = await context.banksClient.getAccount(adminAccount);
+    expect(accountInfo?.data.length).toBe(program.account.admin.size);
+    expect(accountInfo?.lamports.toString()).toBe(
+      rent.minimumBalance(BigInt(program.account.admin.size)).toString()
+    );
+
+    const adminAccountData = await program.account.admin.fetch(adminAccount);
+    expect(adminAccountData.version).toBe(1);
+    expect(adminAccountData.battlePrice.toNumber()).toBe(10_000);
+    expect(adminAccountData.maxStake.toString()).toBe(
+      new anchor.BN(2).pow(new anchor.BN(64)).subn(1).toString()
+    );
+    expect(adminAccountData.configManager.toBase58()).toBe(
+      admin.publicKey.toBase58()
+    );
+    expect(adminAccountData.treasuryTokenAccount.toBase58()).toBe(
+      treasuryTokenAccount.toBase58()
+    );
+    expect(adminAccountData.resultAuthority.toBase58()).toBe(
+      resultAuthority.toBase58()
+    );
+  });
+});
@@ -0,0 +1,128 @@
+import * as anchor from '@coral-xyz/anchor';
+
+import { BankrunProvider, startAnchor } from 'anchor-bankrun';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from '@solana/web3.js';
+
+import { Program } from '@coral-xyz/anchor';
+import { IDL, ScoogiBattle } from '../target/types/scoogi_battle';
+import path from 'path';
+
+const PROGRAM_ID = new PublicKey(
+  'GM9mV2Km6iwaQsVjs5x4wb4CL2XBemS3cgZ28sCydiBR'
+);
+const BATTLE_SEED = Buffer.from('battle');
+
+// Battles written before version 3 are 32 bytes short of the current layout
+// and fail to deserialize until they are migrated.
+describe('migrate_battle', () => {
+  it('grows a version 1 battle and fills in its deposits', async () => {
+    const payer = Keypair.generate();
+    const playerOne = Keypair.generate().publicKey;
+    const playerTwo = Keypair.generate().publicKey;
+    const battleId = new anchor.BN(1);
+    const stakeAmount = new anchor.BN(10 * LAMPORTS_PER_SOL);
+    const [battleAccount] = PublicKey.findProgramAddressSync(
+      [
+        BATTLE_SEED,
+        playerOne.toBuffer(),
+        battleId.toArrayLike(Buffer, 'le', 8),
+      ],
+      PROGRAM_ID
+    );
+
+    const context = await startAnchor(
+      path.resolve(__dirname, '..'),
+      [],
+      [
+        {
+          address: payer.publicKey,
+          info: {
+            lamports: 10 * LAMPORTS_PER_SOL,
+            data: Buffer.alloc(0),
+            owner: SystemProgram.programId,
+            executable: false,
+          },
+        },
+      ]
+    );
+
+    const provider = new BankrunProvider(context);
+    const program = new Program<ScoogiBattle>(IDL, PROGRAM_ID, provider);
+    const battleSize = program.account.battle.size;
+
+    // a version 1 battle never recorded deposits, and with no invited opponent
+    // its current encoding only differs by the 32 trailing zero bytes
+    const encoded = await program.coder.accounts.encode('battle', {
+      battleId,
+      playerOne,
+      playerTwo,
+      winner: PublicKey.default,
+      battleStatus: { inProgress: {} },
+      settlementMode: { oracle: {} },
+      mint: Keypair.generate().publicKey,
+      stakeAmount,
+      feeSchedule: {
+        burnFeeBps: new anchor.BN(100),
+        treasuryFeeBps: new anchor.BN(0),
+        referrerFeeBps: new anchor.BN(0),
+      },
+      treasuryTokenAccount: Keypair.generate().publicKey,
+      playerOneAttestation: null,
+      playerTwoAttestation: null,
+      disputeDeadline: new anchor.BN(0),
+      createdAt: new anchor.BN(1),
+      joinedAt: new anchor.BN(2),
+      playerOneReferrer: PublicKey.default,
+      playerTwoReferrer: PublicKey.default,
+      version: 1,
+      playerOneDeposit: new anchor.BN(0),
+      playerTwoDeposit: new anchor.BN(0),
+      ratingBand: null,
+      invitedOpponent: PublicKey.default,
+      reserved: new Array(7).fill(0),
+    });
+    const legacyData = Buffer.alloc(battleSize - 32);
+    encoded.copy(legacyData, 0, 0, legacyData.length);
+
+    const rent = await context.banksClient.getRent();
+    context.setAccount(battleAccount, {
+      lamports: Number(rent.minimumBalance(BigInt(legacyData.length))),
+      data: legacyData,
+      owner: PROGRAM_ID,
+      executable: false,
+    });
+
+    await expect(program.account.battle.fetch(battleAccount)).rejects.toThrow();
+
+    await program.methods
+      .migrateBattle()
+      .accounts({
+        payer: payer.publicKey,
+        battleAccount,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+
+    const accountInfo = await context.banksClient.getAccount(battleAccount);
+    expect(accountInfo?.data.length).toBe(battleSize);
+
+    const battleAccountData = await program.account.battle.fetch(battleAccount);
+    expect(battleAccountData.version).toBe(3);
+    expect(battleAccountData.battleStatus).toStrictEqual({ inProgress: {} });
+    expect(battleAccountData.playerOneDeposit.toString()).toBe(
+      stakeAmount.toString()
+    );
+    expect(battleAccountData.playerTwoDeposit.toString()).toBe(
+      stakeAmount.toString()
+    );
+    expect(battleAccountData.invitedOpponent.toBase58()).toBe(
+      PublicKey.default.toBase58()
+    );
+  });
+});
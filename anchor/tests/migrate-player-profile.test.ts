@@ -0,0 +1,93 @@
+import * as anchor from '@coral-xyz/anchor';
+
+import { BankrunProvider, startAnchor } from 'anchor-bankrun';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from '@solana/web3.js';
+
+import { Program } from '@coral-xyz/anchor';
+import { IDL, ScoogiBattle } from '../target/types/scoogi_battle';
+import path from 'path';
+
+const PROGRAM_ID = new PublicKey(
+  'GM9mV2Km6iwaQsVjs5x4wb4CL2XBemS3cgZ28sCydiBR'
+);
+const PLAYER_PROFILE_SEED = Buffer.from('player_profile');
+
+// Profiles written before ratings fail to deserialize, and so block their
+// player's battles from settling, until they are migrated.
+describe('migrate_player_profile', () => {
+  it('grows a first layout profile and starts its rating', async () => {
+    const payer = Keypair.generate();
+    const player = Keypair.generate().publicKey;
+    const [playerProfile] = PublicKey.findProgramAddressSync(
+      [PLAYER_PROFILE_SEED, player.toBuffer()],
+      PROGRAM_ID
+    );
+
+    // the first layout: player, wins, losses, total_wagered, total_won
+    const legacyData = Buffer.concat([
+      anchor.BorshAccountsCoder.accountDiscriminator('PlayerProfile'),
+      player.toBuffer(),
+      new anchor.BN(2).toArrayLike(Buffer, 'le', 8),
+      new anchor.BN(1).toArrayLike(Buffer, 'le', 8),
+      new anchor.BN(3_000).toArrayLike(Buffer, 'le', 8),
+      new anchor.BN(3_960).toArrayLike(Buffer, 'le', 8),
+    ]);
+
+    const context = await startAnchor(
+      path.resolve(__dirname, '..'),
+      [],
+      [
+        {
+          address: payer.publicKey,
+          info: {
+            lamports: 10 * LAMPORTS_PER_SOL,
+            data: Buffer.alloc(0),
+            owner: SystemProgram.programId,
+            executable: false,
+          },
+        },
+      ]
+    );
+
+    const rent = await context.banksClient.getRent();
+    context.setAccount(playerProfile, {
+      lamports: Number(rent.minimumBalance(BigInt(legacyData.length))),
+      data: legacyData,
+      owner: PROGRAM_ID,
+      executable: false,
+    });
+
+    const provider = new BankrunProvider(context);
+    const program = new Program<ScoogiBattle>(IDL, PROGRAM_ID, provider);
+
+    await expect(
+      program.account.playerProfile.fetch(playerProfile)
+    ).rejects.toThrow();
+
+    await program.methods
+      .migratePlayerProfile()
+      .accounts({
+        payer: payer.publicKey,
+        playerProfile,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
+      .rpc();
+
+    const accountInfo = await context.banksClient.getAccount(playerProfile);
+    expect(accountInfo?.data.length).toBe(program.account.playerProfile.size);
+
+    const profile = await program.account.playerProfile.fetch(playerProfile);
+    expect(profile.version).toBe(2);
+    expect(profile.rating).toBe(1200);
+    expect(profile.wins.toNumber()).toBe(2);
+    expect(profile.losses.toNumber()).toBe(1);
+    // the totals mixed units across mints and are zeroed
+    expect(profile.retired.every((byte) => byte === 0)).toBe(true);
+  });
+});
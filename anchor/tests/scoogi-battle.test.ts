@@ -6,19 +6,28 @@ import {
   TOKEN_PROGRAM_ID,
   createMint,
   getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
   getOrCreateAssociatedTokenAccount,
   mintTo,
+  transfer,
 } from '@solana/spl-token';
 import {
   Connection,
+  Ed25519Program,
   Keypair,
   LAMPORTS_PER_SOL,
   PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   SystemProgram,
 } from '@solana/web3.js';
 
 import { Program } from '@coral-xyz/anchor';
 import { ScoogiBattle } from '../target/types/scoogi_battle';
+import {
+  getBattleResultMessage,
+  getOpenBattlesByStake,
+} from '../src/scoogi-battle-exports';
 
 async function airdrop(
   connection: Connection,
@@ -34,6 +43,19 @@ async function airdrop(
 const ADMIN_SEED = Buffer.from('admin');
 const BATTLE_SEED = Buffer.from('battle');
 const TOKEN_ACCOUNT_SEED = Buffer.from('token_account');
+const MINT_CONFIG_SEED = Buffer.from('mint_config');
+const PENDING_MINT_CONFIG_SEED = Buffer.from('pending_mint_config');
+const SOL_VAULT_SEED = Buffer.from('sol_vault');
+const PLAYER_PROFILE_SEED = Buffer.from('player_profile');
+const PAUSE_CREATE = 1 << 0;
+const PAUSE_JOIN = 1 << 1;
+// prefixes the self CPIs `emit_cpi!` logs events through
+const EVENT_IX_TAG = Buffer.from([
+  0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d,
+]);
+const INCINERATOR = new PublicKey(
+  '1nc1nerator11111111111111111111111111111111'
+);
 
 describe('🐸 Scoogi Battle 🤺', () => {
   // Configure the client to use the local cluster.
@@ -55,16 +77,218 @@ describe('🐸 Scoogi Battle 🤺', () => {
   const admin = anchor.Wallet.local();
   const playerOne = Keypair.generate();
   const playerTwo = Keypair.generate();
+  const resultAuthority = Keypair.generate();
+  const [playerOneProfile] = PublicKey.findProgramAddressSync(
+    [PLAYER_PROFILE_SEED, playerOne.publicKey.toBuffer()],
+    program.programId
+  );
+  const [playerTwoProfile] = PublicKey.findProgramAddressSync(
+    [PLAYER_PROFILE_SEED, playerTwo.publicKey.toBuffer()],
+    program.programId
+  );
   const [adminAccount] = PublicKey.findProgramAddressSync(
     [ADMIN_SEED],
     program.programId
   );
 
+  function getBattleAddresses(player: PublicKey, battleId: anchor.BN) {
+    const battleIdBuffer = battleId.toArrayLike(Buffer, 'le', 8);
+
+    const [battleAccount] = PublicKey.findProgramAddressSync(
+      [BATTLE_SEED, player.toBuffer(), battleIdBuffer],
+      program.programId
+    );
+
+    const [battleTokenAccount] = PublicKey.findProgramAddressSync(
+      [TOKEN_ACCOUNT_SEED, player.toBuffer(), battleIdBuffer],
+      program.programId
+    );
+
+    return [battleAccount, battleTokenAccount];
+  }
+
+  async function createAndJoinBattle(
+    battleId: anchor.BN,
+    settlementMode: object,
+    referrer: PublicKey | null = null
+  ) {
+    const [battleAccount, battleTokenAccount] = getBattleAddresses(
+      playerOne.publicKey,
+      battleId
+    );
+
+    let tx = await program.methods
+      .createBattle(battleId, settlementMode, referrer, null, null, null)
+      .accounts({
+        playerOne: playerOne.publicKey,
+        adminAccount,
+        mintConfig: null,
+        battleAccount,
+        playerOneTokenAccount: playerOneTokenAccount.address,
+        battleTokenAccount,
+        mint,
+        playerOneProfile,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([playerOne])
+      .rpc();
+
+    await connection.confirmTransaction(tx, 'confirmed');
+
+    tx = await program.methods
+      .joinBattle(battleId, null)
+      .accounts({
+        playerTwo: playerTwo.publicKey,
+        playerOne: playerOne.publicKey,
+        adminAccount,
+        battleAccount,
+        playerTwoTokenAccount: playerTwoTokenAccount.address,
+        battleTokenAccount,
+        mint,
+        playerTwoProfile,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([playerTwo])
+      .rpc();
+
+    await connection.confirmTransaction(tx, 'confirmed');
+
+    return [battleAccount, battleTokenAccount];
+  }
+
+  async function attestBattleResult(
+    battleId: anchor.BN,
+    player: Keypair,
+    battleResult: number
+  ) {
+    const [battleAccount] = getBattleAddresses(playerOne.publicKey, battleId);
+
+    const tx = await program.methods
+      .attestBattleResult(battleId, battleResult)
+      .accounts({
+        player: player.publicKey,
+        playerOne: playerOne.publicKey,
+        battleAccount,
+      })
+      .signers([player])
+      .rpc();
+
+    await connection.confirmTransaction(tx, 'confirmed');
+
+    return tx;
+  }
+
+  async function recordBattleResult(
+    battleId: anchor.BN,
+    battleResult: number,
+    winner: PublicKey,
+    playerOneReferrerTokenAccount: PublicKey | null = null
+  ) {
+    const [battleAccount, battleTokenAccount] = getBattleAddresses(
+      playerOne.publicKey,
+      battleId
+    );
+
+    const tx = await program.methods
+      .recordBattleResult(battleResult, battleId)
+      .accounts({
+        payer: resultAuthority.publicKey,
+        resultAuthority: resultAuthority.publicKey,
+        winner,
+        playerOne: playerOne.publicKey,
+        playerTwo: playerTwo.publicKey,
+        admin: admin.publicKey,
+        adminAccount,
+        treasuryTokenAccount: adminTokenAccount.address,
+        battleAccount,
+        battleTokenAccount,
+        winnerTokenAccount: getAssociatedTokenAddressSync(mint, winner),
+        playerOneReferrerTokenAccount,
+        playerTwoReferrerTokenAccount: null,
+        mint,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        playerOneProfile,
+        playerTwoProfile,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([resultAuthority])
+      .rpc();
+
+    await connection.confirmTransaction(tx, 'confirmed');
+
+    return tx;
+  }
+
+  async function getCpiEvents(tx: string) {
+    const txData = await connection.getTransaction(tx, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    });
+
+    return (txData?.meta?.innerInstructions ?? [])
+      .flatMap(({ instructions }) => instructions)
+      .map(({ data }) => Buffer.from(anchor.utils.bytes.bs58.decode(data)))
+      .filter((data) => data.subarray(0, 8).equals(EVENT_IX_TAG))
+      .map((data) =>
+        program.coder.events.decode(
+          anchor.utils.bytes.base64.encode(data.subarray(8))
+        )
+      );
+  }
+
+  function getPendingMintConfig(mint: PublicKey) {
+    const [pendingMintConfig] = PublicKey.findProgramAddressSync(
+      [PENDING_MINT_CONFIG_SEED, mint.toBuffer()],
+      program.programId
+    );
+
+    return pendingMintConfig;
+  }
+
+  function applyMintConfig(mint: PublicKey) {
+    const [mintConfig] = PublicKey.findProgramAddressSync(
+      [MINT_CONFIG_SEED, mint.toBuffer()],
+      program.programId
+    );
+
+    return program.methods
+      .applyMintConfig()
+      .accounts({
+        payer: admin.publicKey,
+        adminAccount,
+        configManager: admin.publicKey,
+        pendingMintConfig: getPendingMintConfig(mint),
+        mintConfig,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  async function updateDisputeWindow(disputeWindowSeconds: number) {
+    const tx = await program.methods
+      .updateDisputeWindow(new anchor.BN(disputeWindowSeconds))
+      .accounts({
+        configManager: admin.publicKey,
+        adminAccount,
+      })
+      .signers([admin.payer])
+      .rpc();
+
+    await connection.confirmTransaction(tx, 'confirmed');
+  }
+
   beforeAll(async () => {
     try {
       await airdrop(connection, admin.publicKey);
       await airdrop(connection, playerOne.publicKey);
       await airdrop(connection, playerTwo.publicKey);
+      await airdrop(connection, resultAuthority.publicKey);
     } catch (error) {
       console.error('Error:', error);
     }
@@ -116,14 +340,9 @@ describe('🐸 Scoogi Battle 🤺', () => {
       100_000 * LAMPORTS_PER_SOL
     );
 
-    [battleTokenAccountAddress] = PublicKey.findProgramAddressSync(
-      [TOKEN_ACCOUNT_SEED, playerOne.publicKey.toBuffer()],
-      program.programId
-    );
-
-    [battleAccountAddress] = PublicKey.findProgramAddressSync(
-      [BATTLE_SEED, playerOne.publicKey.toBuffer()],
-      program.programId
+    [battleAccountAddress, battleTokenAccountAddress] = getBattleAddresses(
+      playerOne.publicKey,
+      startBattleId
     );
   });
 
@@ -134,6 +353,8 @@ describe('🐸 Scoogi Battle 🤺', () => {
         admin: admin.publicKey,
         adminAccount,
         mint,
+        treasuryTokenAccount: adminTokenAccount.address,
+        resultAuthority: resultAuthority.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([admin.payer])
@@ -146,6 +367,9 @@ describe('🐸 Scoogi Battle 🤺', () => {
     expect(adminAccountData.admin.toBase58()).toBe(admin.publicKey.toBase58());
     expect(adminAccountData.mint.toBase58()).toBe(mint.toBase58());
     expect(adminAccountData.burnFeeBps.toString()).toBe(burnFeeBps.toString());
+    expect(adminAccountData.resultAuthority.toBase58()).toBe(
+      resultAuthority.publicKey.toBase58()
+    );
     expect(adminAccountData.battlePrice.toString()).toBe(
       battlePrice.mul(new anchor.BN(LAMPORTS_PER_SOL)).toString()
     );
@@ -158,14 +382,16 @@ describe('🐸 Scoogi Battle 🤺', () => {
       );
 
       const tx = await program.methods
-        .createBattle(startBattleId)
+        .createBattle(startBattleId, { oracle: {} }, null, null, null, null)
         .accounts({
           playerOne: playerOne.publicKey,
           adminAccount,
+          mintConfig: null,
           battleAccount: battleAccountAddress,
           playerOneTokenAccount: playerOneTokenAccount.address,
           battleTokenAccount: battleTokenAccountAddress,
           mint,
+          playerOneProfile,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -205,7 +431,7 @@ describe('🐸 Scoogi Battle 🤺', () => {
       );
 
       const tx = await program.methods
-        .joinBattle(startBattleId)
+        .joinBattle(startBattleId, null)
         .accounts({
           playerTwo: playerTwo.publicKey,
           playerOne: playerOne.publicKey,
@@ -214,6 +440,7 @@ describe('🐸 Scoogi Battle 🤺', () => {
           playerTwoTokenAccount: playerTwoTokenAccount.address,
           battleTokenAccount: battleTokenAccountAddress,
           mint,
+          playerTwoProfile,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -247,6 +474,37 @@ describe('🐸 Scoogi Battle 🤺', () => {
       expect(battleAccountData.battleStatus).toStrictEqual({ inProgress: {} });
     });
 
+    it('Rejects a result not signed by the result authority', async () => {
+      await expect(
+        program.methods
+          .recordBattleResult(0, startBattleId)
+          .accounts({
+            payer: playerOne.publicKey,
+            resultAuthority: playerOne.publicKey,
+            winner: playerOne.publicKey,
+            playerOne: playerOne.publicKey,
+            playerTwo: playerTwo.publicKey,
+            admin: admin.publicKey,
+            adminAccount,
+            treasuryTokenAccount: adminTokenAccount.address,
+            battleAccount: battleAccountAddress,
+            battleTokenAccount: battleTokenAccountAddress,
+            winnerTokenAccount: playerOneTokenAccount.address,
+            playerOneReferrerTokenAccount: null,
+            playerTwoReferrerTokenAccount: null,
+            mint,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            playerOneProfile,
+            playerTwoProfile,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerOne])
+          .rpc()
+      ).rejects.toThrow();
+    });
+
     it('Records a battle', async () => {
       const battleResult = 0;
       const winner = playerOne;
@@ -260,25 +518,33 @@ describe('🐸 Scoogi Battle 🤺', () => {
         connection,
         battleTokenAccountAddress
       );
+      const beforeMint = await getMint(connection, mint);
 
       const tx = await program.methods
         .recordBattleResult(battleResult, startBattleId)
         .accounts({
+          payer: resultAuthority.publicKey,
+          resultAuthority: resultAuthority.publicKey,
           winner: winner.publicKey,
           playerOne: playerOne.publicKey,
           playerTwo: playerTwo.publicKey,
           admin: admin.publicKey,
           adminAccount,
-          adminTokenAccount: adminTokenAccount.address,
+          treasuryTokenAccount: adminTokenAccount.address,
           battleAccount: battleAccountAddress,
           battleTokenAccount: battleTokenAccountAddress,
           winnerTokenAccount: winnerTokenAccount.address,
+          playerOneReferrerTokenAccount: null,
+          playerTwoReferrerTokenAccount: null,
           mint,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          playerOneProfile,
+          playerTwoProfile,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
-        .signers([winner])
+        .signers([resultAuthority])
         .rpc();
 
       await connection.confirmTransaction(tx, 'confirmed');
@@ -299,12 +565,50 @@ describe('🐸 Scoogi Battle 🤺', () => {
           .toString()
       );
 
+      const [settled] = await getCpiEvents(tx);
+      expect(settled?.name).toBe('BattleSettled');
+      expect(settled?.data.winner.toBase58()).toBe(
+        playerOne.publicKey.toBase58()
+      );
+      expect(settled?.data.burnAmount.toString()).toBe(burnAmount.toString());
+
+      // the whole fee is burned while the treasury share is 0
+      const afterMint = await getMint(connection, mint);
+      expect((beforeMint.supply - afterMint.supply).toString()).toBe(
+        burnAmount.toString()
+      );
+
       try {
         await program.account.battle.fetch(battleAccountAddress);
       } catch (error) {
         expect(error).toBeInstanceOf(Error);
       }
     });
+
+    it('Records the battle on both player profiles', async () => {
+      const winnerProfile = await program.account.playerProfile.fetch(
+        playerOneProfile
+      );
+      const loserProfile = await program.account.playerProfile.fetch(
+        playerTwoProfile
+      );
+
+      expect(winnerProfile.player.toBase58()).toBe(
+        playerOne.publicKey.toBase58()
+      );
+      expect(winnerProfile.wins.toNumber()).toBe(1);
+      expect(winnerProfile.losses.toNumber()).toBe(0);
+
+      expect(loserProfile.player.toBase58()).toBe(
+        playerTwo.publicKey.toBase58()
+      );
+      expect(loserProfile.wins.toNumber()).toBe(0);
+      expect(loserProfile.losses.toNumber()).toBe(1);
+
+      // evenly rated players trade half the K factor
+      expect(winnerProfile.rating).toBe(1216);
+      expect(loserProfile.rating).toBe(1184);
+    });
   });
 
   describe('Full battle w/ Player Two as Winner', () => {
@@ -314,14 +618,16 @@ describe('🐸 Scoogi Battle 🤺', () => {
       );
 
       const tx = await program.methods
-        .createBattle(startBattleId)
+        .createBattle(startBattleId, { oracle: {} }, null, null, null, null)
         .accounts({
           playerOne: playerOne.publicKey,
           adminAccount,
+          mintConfig: null,
           battleAccount: battleAccountAddress,
           playerOneTokenAccount: playerOneTokenAccount.address,
           battleTokenAccount: battleTokenAccountAddress,
           mint,
+          playerOneProfile,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -361,7 +667,7 @@ describe('🐸 Scoogi Battle 🤺', () => {
       );
 
       const tx = await program.methods
-        .joinBattle(startBattleId)
+        .joinBattle(startBattleId, null)
         .accounts({
           playerTwo: playerTwo.publicKey,
           playerOne: playerOne.publicKey,
@@ -370,6 +676,7 @@ describe('🐸 Scoogi Battle 🤺', () => {
           playerTwoTokenAccount: playerTwoTokenAccount.address,
           battleTokenAccount: battleTokenAccountAddress,
           mint,
+          playerTwoProfile,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -403,7 +710,7 @@ describe('🐸 Scoogi Battle 🤺', () => {
       expect(battleAccountData.battleStatus).toStrictEqual({ inProgress: {} });
     });
 
-    it('Records a battle', async () => {
+    it('Records a battle relayed with a signed result', async () => {
       const battleResult = 1;
       const winner = playerTwo;
       const winnerTokenAccount = playerTwoTokenAccount;
@@ -417,25 +724,59 @@ describe('🐸 Scoogi Battle 🤺', () => {
         battleTokenAccountAddress
       );
 
-      const tx = await program.methods
-        .recordBattleResult(battleResult, startBattleId)
-        .accounts({
-          winner: winner.publicKey,
-          playerOne: playerOne.publicKey,
-          playerTwo: playerTwo.publicKey,
-          admin: admin.publicKey,
-          adminAccount,
-          adminTokenAccount: adminTokenAccount.address,
-          battleAccount: battleAccountAddress,
-          battleTokenAccount: battleTokenAccountAddress,
-          winnerTokenAccount: winnerTokenAccount.address,
-          mint,
-          systemProgram: SystemProgram.programId,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([winner])
-        .rpc();
+      const { createdAt } = await program.account.battle.fetch(
+        battleAccountAddress
+      );
+
+      // the result authority signs off-chain and player two relays it
+      function relaySignedResult(signedCreatedAt: anchor.BN) {
+        const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: resultAuthority.secretKey,
+          message: getBattleResultMessage(
+            program.programId,
+            startBattleId,
+            signedCreatedAt,
+            playerOne.publicKey,
+            playerTwo.publicKey,
+            winner.publicKey
+          ),
+        });
+
+        return program.methods
+          .recordBattleResult(battleResult, startBattleId)
+          .preInstructions([ed25519Ix])
+          .accounts({
+            payer: playerTwo.publicKey,
+            resultAuthority: resultAuthority.publicKey,
+            winner: winner.publicKey,
+            playerOne: playerOne.publicKey,
+            playerTwo: playerTwo.publicKey,
+            admin: admin.publicKey,
+            adminAccount,
+            treasuryTokenAccount: adminTokenAccount.address,
+            battleAccount: battleAccountAddress,
+            battleTokenAccount: battleTokenAccountAddress,
+            winnerTokenAccount: winnerTokenAccount.address,
+            playerOneReferrerTokenAccount: null,
+            playerTwoReferrerTokenAccount: null,
+            mint,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            playerOneProfile,
+            playerTwoProfile,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerTwo])
+          .rpc();
+      }
+
+      // a result signed for an earlier battle under the same id is rejected
+      await expect(relaySignedResult(createdAt.subn(1))).rejects.toThrow(
+        /InvalidResultSignature/
+      );
+
+      const tx = await relaySignedResult(createdAt);
 
       await connection.confirmTransaction(tx, 'confirmed');
 
@@ -461,20 +802,35 @@ describe('🐸 Scoogi Battle 🤺', () => {
         expect(error).toBeInstanceOf(Error);
       }
     });
+
+    it('Moves more rating to the lower rated winner', async () => {
+      const playerOneData = await program.account.playerProfile.fetch(
+        playerOneProfile
+      );
+      const playerTwoData = await program.account.playerProfile.fetch(
+        playerTwoProfile
+      );
+
+      // player two came in at 1184 against player one's 1216
+      expect(playerTwoData.rating).toBe(1184 + 17);
+      expect(playerOneData.rating).toBe(1216 - 17);
+    });
   });
 
   describe('Battle with withdraw', () => {
     it('withdraws', async () => {
       startBattleId.add(new anchor.BN(new Date().getTime()));
       let tx = await program.methods
-        .createBattle(startBattleId)
+        .createBattle(startBattleId, { oracle: {} }, null, null, null, null)
         .accounts({
           playerOne: playerOne.publicKey,
           adminAccount,
+          mintConfig: null,
           battleAccount: battleAccountAddress,
           playerOneTokenAccount: playerOneTokenAccount.address,
           battleTokenAccount: battleTokenAccountAddress,
           mint,
+          playerOneProfile,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -511,19 +867,43 @@ describe('🐸 Scoogi Battle 🤺', () => {
     });
   });
 
-  describe('Admin', () => {
-    it('withdraws', async () => {
-      startBattleId.add(new anchor.BN(new Date().getTime()));
+  describe('Dual confirmation', () => {
+    it('settles when both players attest the same result', async () => {
+      const battleId = startBattleId.add(new anchor.BN(10));
+      const [battleAccount, battleTokenAccount] = await createAndJoinBattle(
+        battleId,
+        { dualConfirmation: {} }
+      );
 
-      let tx = await program.methods
-        .createBattle(startBattleId)
+      await attestBattleResult(battleId, playerOne, 1);
+      await attestBattleResult(battleId, playerTwo, 1);
+
+      const beforeWinnerTokenAccount = await getAccount(
+        connection,
+        playerTwoTokenAccount.address
+      );
+
+      // anyone can settle once the attestations agree
+      const tx = await program.methods
+        .recordBattleResult(1, battleId)
         .accounts({
+          payer: playerOne.publicKey,
+          resultAuthority: resultAuthority.publicKey,
+          winner: playerTwo.publicKey,
           playerOne: playerOne.publicKey,
+          playerTwo: playerTwo.publicKey,
+          admin: admin.publicKey,
           adminAccount,
-          battleAccount: battleAccountAddress,
-          playerOneTokenAccount: playerOneTokenAccount.address,
-          battleTokenAccount: battleTokenAccountAddress,
+          treasuryTokenAccount: adminTokenAccount.address,
+          battleAccount,
+          battleTokenAccount,
+          winnerTokenAccount: playerTwoTokenAccount.address,
+          playerOneReferrerTokenAccount: null,
+          playerTwoReferrerTokenAccount: null,
           mint,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          playerOneProfile,
+          playerTwoProfile,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -533,37 +913,137 @@ describe('🐸 Scoogi Battle 🤺', () => {
 
       await connection.confirmTransaction(tx, 'confirmed');
 
-      tx = await program.methods
-        .joinBattle(startBattleId)
+      const afterWinnerTokenAccount = await getAccount(
+        connection,
+        playerTwoTokenAccount.address
+      );
+
+      expect(
+        afterWinnerTokenAccount.amount > beforeWinnerTokenAccount.amount
+      ).toBe(true);
+    });
+
+    it('moves conflicting attestations into a dispute', async () => {
+      const battleId = startBattleId.add(new anchor.BN(11));
+      const [battleAccount, battleTokenAccount] = await createAndJoinBattle(
+        battleId,
+        { dualConfirmation: {} }
+      );
+
+      await attestBattleResult(battleId, playerOne, 0);
+      const attestTx = await attestBattleResult(battleId, playerTwo, 1);
+
+      const battleAccountData = await program.account.battle.fetch(
+        battleAccount
+      );
+      expect(battleAccountData.battleStatus).toStrictEqual({ disputed: {} });
+
+      const [disputed] = await getCpiEvents(attestTx);
+      expect(disputed?.name).toBe('BattleDisputed');
+      expect(disputed?.data.disputedBy.toBase58()).toBe(
+        playerTwo.publicKey.toBase58()
+      );
+
+      // the admin refunds both players
+      const tx = await program.methods
+        .adminWithdrawal(battleId)
         .accounts({
-          playerTwo: playerTwo.publicKey,
+          treasuryAuthority: admin.publicKey,
           playerOne: playerOne.publicKey,
+          playerTwo: playerTwo.publicKey,
+          admin: admin.publicKey,
           adminAccount,
-          battleAccount: battleAccountAddress,
+          battleAccount,
+          battleTokenAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
           playerTwoTokenAccount: playerTwoTokenAccount.address,
-          battleTokenAccount: battleTokenAccountAddress,
+          treasuryTokenAccount: adminTokenAccount.address,
           mint,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    });
+  });
+
+  describe('Dispute window', () => {
+    afterAll(async () => {
+      await updateDisputeWindow(0);
+    });
+
+    it('rejects a dispute window above the maximum', async () => {
+      await expect(updateDisputeWindow(31 * 24 * 60 * 60)).rejects.toThrow(
+        /InvalidDisputeWindow/
+      );
+    });
+
+    it('lets the loser dispute and the admin resolve it', async () => {
+      await updateDisputeWindow(60);
+
+      const battleId = startBattleId.add(new anchor.BN(20));
+      const [battleAccount, battleTokenAccount] = await createAndJoinBattle(
+        battleId,
+        { oracle: {} }
+      );
+
+      const recordTx = await recordBattleResult(
+        battleId,
+        0,
+        playerOne.publicKey
+      );
+
+      let battleAccountData = await program.account.battle.fetch(
+        battleAccount
+      );
+      expect(battleAccountData.battleStatus).toStrictEqual({
+        resultRecorded: {},
+      });
+
+      const [recorded] = await getCpiEvents(recordTx);
+      expect(recorded?.name).toBe('BattleResultRecorded');
+      expect(recorded?.data.disputeDeadline.toString()).toBe(
+        battleAccountData.disputeDeadline.toString()
+      );
+
+      let tx = await program.methods
+        .disputeBattleResult(battleId)
+        .accounts({
+          player: playerTwo.publicKey,
+          playerOne: playerOne.publicKey,
+          battleAccount,
+        })
         .signers([playerTwo])
         .rpc();
 
       await connection.confirmTransaction(tx, 'confirmed');
 
+      battleAccountData = await program.account.battle.fetch(battleAccount);
+      expect(battleAccountData.battleStatus).toStrictEqual({ disputed: {} });
+
+      const [disputed] = await getCpiEvents(tx);
+      expect(disputed?.name).toBe('BattleDisputed');
+
       tx = await program.methods
-        .adminWithdrawal(startBattleId)
+        .resolveDispute(battleId, { refund: {} })
         .accounts({
           admin: admin.publicKey,
           playerOne: playerOne.publicKey,
           playerTwo: playerTwo.publicKey,
           adminAccount,
-          battleAccount: battleAccountAddress,
-          battleTokenAccount: battleTokenAccountAddress,
+          treasuryTokenAccount: adminTokenAccount.address,
+          battleAccount,
+          battleTokenAccount,
           playerOneTokenAccount: playerOneTokenAccount.address,
           playerTwoTokenAccount: playerTwoTokenAccount.address,
+          playerOneReferrerTokenAccount: null,
+          playerTwoReferrerTokenAccount: null,
           mint,
+          playerOneProfile,
+          playerTwoProfile,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -573,81 +1053,1942 @@ describe('🐸 Scoogi Battle 🤺', () => {
 
       await connection.confirmTransaction(tx, 'confirmed');
 
-      try {
-        await program.account.battle.fetch(battleAccountAddress);
-      } catch (error) {
-        expect(error).toBeInstanceOf(Error);
-      }
+      await expect(
+        program.account.battle.fetch(battleAccount)
+      ).rejects.toThrow();
     });
 
-    it('updates battle price', async () => {
-      const newBattlePrice = new anchor.BN(20_000);
+    it('finalizes an undisputed result once the window passes', async () => {
+      await updateDisputeWindow(1);
+
+      const battleId = startBattleId.add(new anchor.BN(21));
+      const [battleAccount, battleTokenAccount] = await createAndJoinBattle(
+        battleId,
+        { oracle: {} }
+      );
+
+      await recordBattleResult(battleId, 1, playerTwo.publicKey);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
 
       const tx = await program.methods
-        .updateBattlePrice(newBattlePrice)
+        .finalizeBattle(battleId)
         .accounts({
+          payer: playerTwo.publicKey,
+          winner: playerTwo.publicKey,
+          playerOne: playerOne.publicKey,
           admin: admin.publicKey,
           adminAccount,
+          treasuryTokenAccount: adminTokenAccount.address,
+          battleAccount,
+          battleTokenAccount,
+          winnerTokenAccount: playerTwoTokenAccount.address,
+          playerOneReferrerTokenAccount: null,
+          playerTwoReferrerTokenAccount: null,
           mint,
+          playerOneProfile,
+          playerTwoProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
-        .signers([admin.payer])
+        .signers([playerTwo])
         .rpc();
 
       await connection.confirmTransaction(tx, 'confirmed');
 
-      const adminAccountData = await program.account.admin.fetch(adminAccount);
-
-      expect(adminAccountData.battlePrice.toString()).toBe(
-        newBattlePrice.mul(new anchor.BN(LAMPORTS_PER_SOL)).toString()
-      );
+      await expect(
+        program.account.battle.fetch(battleAccount)
+      ).rejects.toThrow();
     });
+  });
 
-    it('updates burn fee bps', async () => {
-      const newBurnFeeBps = new anchor.BN(200);
-
+  describe('Battle timeout', () => {
+    async function updateBattleTimeout(battleTimeoutSeconds: number) {
       const tx = await program.methods
-        .updateBurnFeeBps(newBurnFeeBps)
+        .updateBattleTimeout(new anchor.BN(battleTimeoutSeconds))
         .accounts({
-          admin: admin.publicKey,
+          configManager: admin.publicKey,
           adminAccount,
-          systemProgram: SystemProgram.programId,
         })
         .signers([admin.payer])
         .rpc();
 
       await connection.confirmTransaction(tx, 'confirmed');
+    }
 
-      const adminAccountData = await program.account.admin.fetch(adminAccount);
+    afterAll(async () => {
+      await updateBattleTimeout(0);
+    });
 
-      expect(adminAccountData.burnFeeBps.toString()).toBe(
-        newBurnFeeBps.toString()
+    it('lets anyone refund a stuck battle after the timeout', async () => {
+      await updateBattleTimeout(1);
+
+      const battleId = startBattleId.add(new anchor.BN(30));
+      const [battleAccount, battleTokenAccount] = await createAndJoinBattle(
+        battleId,
+        { oracle: {} }
       );
-    });
 
-    it('updates the mint', async () => {
-      const newMint = await createMint(
+      const beforePlayerTwoTokenAccount = await getAccount(
         connection,
-        admin.payer,
-        admin.publicKey,
-        admin.publicKey,
-        9
+        playerTwoTokenAccount.address
       );
 
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      const relayer = Keypair.generate();
+      await airdrop(connection, relayer.publicKey);
+
+      const beforeAdminBalance = await connection.getBalance(admin.publicKey);
+
       const tx = await program.methods
-        .updateMint()
+        .expireBattle(battleId)
         .accounts({
+          payer: relayer.publicKey,
+          playerOne: playerOne.publicKey,
+          playerTwo: playerTwo.publicKey,
           admin: admin.publicKey,
           adminAccount,
-          mint: newMint,
+          battleAccount,
+          battleTokenAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          playerTwoTokenAccount: playerTwoTokenAccount.address,
+          mint,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
-        .signers([admin.payer])
+        .signers([relayer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const afterPlayerTwoTokenAccount = await getAccount(
+        connection,
+        playerTwoTokenAccount.address
+      );
+
+      expect(afterPlayerTwoTokenAccount.amount.toString()).toBe(
+        (
+          beforePlayerTwoTokenAccount.amount +
+          BigInt(battlePrice.mul(new anchor.BN(LAMPORTS_PER_SOL)).toString())
+        ).toString()
+      );
+      await expect(
+        program.account.battle.fetch(battleAccount)
+      ).rejects.toThrow();
+
+      // the closed accounts' rent goes to the admin like every other path
+      expect(await connection.getBalance(admin.publicKey)).toBeGreaterThan(
+        beforeAdminBalance
+      );
+    });
+  });
+
+  describe('Fee schedule', () => {
+    async function updateFeeSchedule(
+      burnFeeBps: number,
+      treasuryFeeBps: number,
+      referrerFeeBps: number
+    ) {
+      const tx = await program.methods
+        .updateFeeSchedule({
+          burnFeeBps: new anchor.BN(burnFeeBps),
+          treasuryFeeBps: new anchor.BN(treasuryFeeBps),
+          referrerFeeBps: new anchor.BN(referrerFeeBps),
+        })
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    }
+
+    afterAll(async () => {
+      await updateFeeSchedule(burnFeeBps.toNumber(), 0, 0);
+    });
+
+    it('rejects fees above 10,000 bps', async () => {
+      await expect(updateFeeSchedule(5_000, 5_000, 1)).rejects.toThrow();
+    });
+
+    it('pays the burn, treasury and referrer shares', async () => {
+      await updateFeeSchedule(100, 100, 50);
+
+      const referrer = Keypair.generate();
+      const referrerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        connection,
+        admin.payer,
+        mint,
+        referrer.publicKey
+      );
+
+      const battleId = startBattleId.add(new anchor.BN(40));
+      await createAndJoinBattle(battleId, { oracle: {} }, referrer.publicKey);
+
+      const beforeTreasuryTokenAccount = await getAccount(
+        connection,
+        adminTokenAccount.address
+      );
+
+      await recordBattleResult(
+        battleId,
+        0,
+        playerOne.publicKey,
+        referrerTokenAccount.address
+      );
+
+      const stake = battlePrice.mul(new anchor.BN(LAMPORTS_PER_SOL));
+      const afterTreasuryTokenAccount = await getAccount(
+        connection,
+        adminTokenAccount.address
+      );
+      const afterReferrerTokenAccount = await getAccount(
+        connection,
+        referrerTokenAccount.address
+      );
+
+      expect(
+        (
+          afterTreasuryTokenAccount.amount - beforeTreasuryTokenAccount.amount
+        ).toString()
+      ).toBe(
+        stake.mul(new anchor.BN(2 * 100)).div(new anchor.BN(10_000)).toString()
+      );
+      expect(afterReferrerTokenAccount.amount.toString()).toBe(
+        stake.mul(new anchor.BN(50)).div(new anchor.BN(10_000)).toString()
+      );
+    });
+  });
+
+  describe('Battle terms', () => {
+    async function updateBattlePrice(price: anchor.BN) {
+      const tx = await program.methods
+        .updateBattlePrice(price)
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          mint,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    }
+
+    afterAll(async () => {
+      await updateBattlePrice(battlePrice);
+    });
+
+    it('keeps the stake from creation when the price changes', async () => {
+      const battleId = startBattleId.add(new anchor.BN(50));
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+      const stake = battlePrice.mul(new anchor.BN(LAMPORTS_PER_SOL));
+
+      let tx = await program.methods
+        .createBattle(battleId, { oracle: {} }, null, null, null, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: null,
+          battleAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          battleTokenAccount,
+          mint,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      await updateBattlePrice(battlePrice.mul(new anchor.BN(2)));
+
+      tx = await program.methods
+        .joinBattle(battleId, null)
+        .accounts({
+          playerTwo: playerTwo.publicKey,
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          battleAccount,
+          playerTwoTokenAccount: playerTwoTokenAccount.address,
+          battleTokenAccount,
+          mint,
+          playerTwoProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerTwo])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const battleAccountData = await program.account.battle.fetch(
+        battleAccount
+      );
+      const battleTokenAccountData = await getAccount(
+        connection,
+        battleTokenAccount
+      );
+
+      expect(battleAccountData.stakeAmount.toString()).toBe(stake.toString());
+      expect(battleAccountData.mint.toBase58()).toBe(mint.toBase58());
+      expect(battleTokenAccountData.amount.toString()).toBe(
+        stake.mul(new anchor.BN(2)).toString()
+      );
+
+      await recordBattleResult(battleId, 0, playerOne.publicKey);
+    });
+  });
+
+  describe('Custom stakes', () => {
+    it('creates a battle with a player-chosen stake', async () => {
+      const battleId = startBattleId.add(new anchor.BN(60));
+      // stakes are in base units, so half a token is a valid wager
+      const stake = new anchor.BN(LAMPORTS_PER_SOL / 2);
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      let tx = await program.methods
+        .createBattle(battleId, { oracle: {} }, null, stake, null, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: null,
+          battleAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          battleTokenAccount,
+          mint,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const openBattles = await getOpenBattlesByStake(program, stake);
+
+      expect(
+        openBattles.some(({ publicKey }) => publicKey.equals(battleAccount))
+      ).toBe(true);
+
+      tx = await program.methods
+        .withdrawFromBattle(battleId)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          battleAccount,
+          battleTokenAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          mint,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    });
+
+    it('rejects a zero stake', async () => {
+      const battleId = startBattleId.add(new anchor.BN(61));
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      await expect(
+        program.methods
+          .createBattle(
+            battleId,
+            { oracle: {} },
+            null,
+            new anchor.BN(0),
+            null,
+            null
+          )
+          .accounts({
+            playerOne: playerOne.publicKey,
+            adminAccount,
+            mintConfig: null,
+            battleAccount,
+            playerOneTokenAccount: playerOneTokenAccount.address,
+            battleTokenAccount,
+            mint,
+            playerOneProfile,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerOne])
+          .rpc()
+      ).rejects.toThrow();
+    });
+  });
+
+  describe('Multi-mint', () => {
+    let otherMint: PublicKey;
+    let otherMintConfig: PublicKey;
+    let playerOneOtherTokenAccount: Account;
+    let adminOtherTokenAccount: Account;
+
+    function createOtherMintBattle(
+      battleId: anchor.BN,
+      mintConfig: PublicKey | null
+    ) {
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      return program.methods
+        .createBattle(battleId, { oracle: {} }, null, null, null, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig,
+          battleAccount,
+          playerOneTokenAccount: playerOneOtherTokenAccount.address,
+          battleTokenAccount,
+          mint: otherMint,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+    }
+
+    beforeAll(async () => {
+      otherMint = await createMint(
+        connection,
+        admin.payer,
+        admin.publicKey,
+        admin.publicKey,
+        6
+      );
+
+      [otherMintConfig] = PublicKey.findProgramAddressSync(
+        [MINT_CONFIG_SEED, otherMint.toBuffer()],
+        program.programId
+      );
+
+      playerOneOtherTokenAccount = await getOrCreateAssociatedTokenAccount(
+        connection,
+        admin.payer,
+        otherMint,
+        playerOne.publicKey
+      );
+
+      adminOtherTokenAccount = await getOrCreateAssociatedTokenAccount(
+        connection,
+        admin.payer,
+        otherMint,
+        admin.publicKey
+      );
+
+      await mintTo(
+        connection,
+        admin.payer,
+        otherMint,
+        playerOneOtherTokenAccount.address,
+        admin.publicKey,
+        1_000 * 10 ** 6
+      );
+    });
+
+    it('rejects a mint that is not whitelisted', async () => {
+      await expect(
+        createOtherMintBattle(startBattleId.add(new anchor.BN(70)), null)
+      ).rejects.toThrow();
+    });
+
+    it('creates a battle in a whitelisted mint', async () => {
+      let tx = await program.methods
+        .addMintConfig(
+          new anchor.BN(100),
+          new anchor.BN(1),
+          new anchor.BN(500),
+          {
+            burnFeeBps: new anchor.BN(0),
+            treasuryFeeBps: new anchor.BN(250),
+            referrerFeeBps: new anchor.BN(0),
+          }
+        )
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          pendingMintConfig: getPendingMintConfig(otherMint),
+          treasuryTokenAccount: adminOtherTokenAccount.address,
+          mint: otherMint,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      // without a config delay the terms can be applied right away
+      await connection.confirmTransaction(
+        await applyMintConfig(otherMint),
+        'confirmed'
+      );
+
+      const battleId = startBattleId.add(new anchor.BN(71));
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      tx = await createOtherMintBattle(battleId, otherMintConfig);
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const battleAccountData = await program.account.battle.fetch(
+        battleAccount
+      );
+      expect(battleAccountData.mint.toBase58()).toBe(otherMint.toBase58());
+      expect(battleAccountData.stakeAmount.toString()).toBe(
+        (100 * 10 ** 6).toString()
+      );
+      expect(battleAccountData.feeSchedule.treasuryFeeBps.toNumber()).toBe(250);
+      expect(battleAccountData.treasuryTokenAccount.toBase58()).toBe(
+        adminOtherTokenAccount.address.toBase58()
+      );
+
+      tx = await program.methods
+        .withdrawFromBattle(battleId)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          battleAccount,
+          battleTokenAccount,
+          playerOneTokenAccount: playerOneOtherTokenAccount.address,
+          mint: otherMint,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    });
+
+    it('stops accepting a removed mint', async () => {
+      const tx = await program.methods
+        .removeMintConfig()
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          mintConfig: otherMintConfig,
+          pendingMintConfig: getPendingMintConfig(otherMint),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+      await connection.confirmTransaction(
+        await applyMintConfig(otherMint),
+        'confirmed'
+      );
+      expect(await connection.getAccountInfo(otherMintConfig)).toBeNull();
+
+      await expect(
+        createOtherMintBattle(
+          startBattleId.add(new anchor.BN(72)),
+          otherMintConfig
+        )
+      ).rejects.toThrow();
+    });
+  });
+
+  describe('SOL battles', () => {
+    const [solConfig] = PublicKey.findProgramAddressSync(
+      [MINT_CONFIG_SEED, PublicKey.default.toBuffer()],
+      program.programId
+    );
+
+    function getSolBattleAddresses(battleId: anchor.BN) {
+      const [battleAccount] = getBattleAddresses(playerOne.publicKey, battleId);
+
+      const [solVault] = PublicKey.findProgramAddressSync(
+        [
+          SOL_VAULT_SEED,
+          playerOne.publicKey.toBuffer(),
+          battleId.toArrayLike(Buffer, 'le', 8),
+        ],
+        program.programId
+      );
+
+      return [battleAccount, solVault];
+    }
+
+    async function createSolBattle(battleId: anchor.BN) {
+      const [battleAccount, solVault] = getSolBattleAddresses(battleId);
+
+      const tx = await program.methods
+        .createSolBattle(battleId, { oracle: {} }, null, null, null, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: solConfig,
+          battleAccount,
+          solVault,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    }
+
+    beforeAll(async () => {
+      await airdrop(connection, playerOne.publicKey);
+      await airdrop(connection, playerTwo.publicKey);
+
+      const tx = await program.methods
+        .addSolConfig(
+          new anchor.BN(1),
+          new anchor.BN(1),
+          new anchor.BN(10),
+          {
+            burnFeeBps: new anchor.BN(100),
+            treasuryFeeBps: new anchor.BN(0),
+            referrerFeeBps: new anchor.BN(1),
+          }
+        )
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          pendingMintConfig: getPendingMintConfig(PublicKey.default),
+          treasury: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+      await connection.confirmTransaction(
+        await applyMintConfig(PublicKey.default),
+        'confirmed'
+      );
+    });
+
+    it('plays a battle staked in SOL', async () => {
+      const battleId = startBattleId.add(new anchor.BN(80));
+      const [battleAccount, solVault] = getSolBattleAddresses(battleId);
+
+      await createSolBattle(battleId);
+
+      let tx = await program.methods
+        .joinSolBattle(battleId, null)
+        .accounts({
+          playerTwo: playerTwo.publicKey,
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          battleAccount,
+          solVault,
+          playerTwoProfile,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerTwo])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const balanceBefore = await connection.getBalance(playerOne.publicKey);
+
+      tx = await program.methods
+        .recordSolBattleResult(0, battleId)
+        .accounts({
+          payer: resultAuthority.publicKey,
+          resultAuthority: resultAuthority.publicKey,
+          winner: playerOne.publicKey,
+          playerOne: playerOne.publicKey,
+          playerTwo: playerTwo.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          treasury: admin.publicKey,
+          battleAccount,
+          solVault,
+          playerOneReferrer: null,
+          playerTwoReferrer: null,
+          incinerator: INCINERATOR,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          playerOneProfile,
+          playerTwoProfile,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([resultAuthority])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      // a 2 SOL pot less the 1% burn
+      const balanceAfter = await connection.getBalance(playerOne.publicKey);
+      expect(balanceAfter - balanceBefore).toBe(1.98 * LAMPORTS_PER_SOL);
+      expect(await connection.getAccountInfo(solVault)).toBeNull();
+      expect(await connection.getAccountInfo(battleAccount)).toBeNull();
+    });
+
+    it('pays an unfundable referrer share to the winner', async () => {
+      const battleId = startBattleId.add(new anchor.BN(82));
+      const [battleAccount, solVault] = getSolBattleAddresses(battleId);
+      // never funded, and 1 bps of a 1 SOL stake is below its rent exemption
+      const referrer = Keypair.generate().publicKey;
+
+      let tx = await program.methods
+        .createSolBattle(battleId, { oracle: {} }, referrer, null, null, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: solConfig,
+          battleAccount,
+          solVault,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      tx = await program.methods
+        .joinSolBattle(battleId, null)
+        .accounts({
+          playerTwo: playerTwo.publicKey,
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          battleAccount,
+          solVault,
+          playerTwoProfile,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerTwo])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const balanceBefore = await connection.getBalance(playerOne.publicKey);
+
+      tx = await program.methods
+        .recordSolBattleResult(0, battleId)
+        .accounts({
+          payer: resultAuthority.publicKey,
+          resultAuthority: resultAuthority.publicKey,
+          winner: playerOne.publicKey,
+          playerOne: playerOne.publicKey,
+          playerTwo: playerTwo.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          treasury: admin.publicKey,
+          battleAccount,
+          solVault,
+          playerOneReferrer: referrer,
+          playerTwoReferrer: null,
+          incinerator: INCINERATOR,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          playerOneProfile,
+          playerTwoProfile,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([resultAuthority])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      // the whole 2 SOL pot less the 1% burn
+      const balanceAfter = await connection.getBalance(playerOne.publicKey);
+      expect(balanceAfter - balanceBefore).toBe(1.98 * LAMPORTS_PER_SOL);
+      expect(await connection.getBalance(referrer)).toBe(0);
+    });
+
+    it('withdraws from an open SOL battle', async () => {
+      const battleId = startBattleId.add(new anchor.BN(81));
+      const [battleAccount, solVault] = getSolBattleAddresses(battleId);
+
+      await createSolBattle(battleId);
+
+      const tx = await program.methods
+        .withdrawFromSolBattle(battleId)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          battleAccount,
+          solVault,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      expect(await connection.getAccountInfo(solVault)).toBeNull();
+      expect(await connection.getAccountInfo(battleAccount)).toBeNull();
+    });
+  });
+
+  describe('Pause', () => {
+    async function setPaused(flags: number, paused: boolean) {
+      const tx = await program.methods
+        .setPaused(flags, paused)
+        .accounts({
+          pauser: admin.publicKey,
+          adminAccount,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    }
+
+    afterAll(async () => {
+      await setPaused(PAUSE_CREATE | PAUSE_JOIN, false);
+    });
+
+    it('blocks new battles but still refunds open ones', async () => {
+      const battleId = startBattleId.add(new anchor.BN(100));
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      function createBattle(battleId: anchor.BN) {
+        const [battleAccount, battleTokenAccount] = getBattleAddresses(
+          playerOne.publicKey,
+          battleId
+        );
+
+        return program.methods
+          .createBattle(battleId, { oracle: {} }, null, null, null, null)
+          .accounts({
+            playerOne: playerOne.publicKey,
+            adminAccount,
+            mintConfig: null,
+            battleAccount,
+            playerOneTokenAccount: playerOneTokenAccount.address,
+            battleTokenAccount,
+            mint,
+            playerOneProfile,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerOne])
+          .rpc();
+      }
+
+      await connection.confirmTransaction(
+        await createBattle(battleId),
+        'confirmed'
+      );
+
+      await setPaused(PAUSE_CREATE | PAUSE_JOIN, true);
+
+      await expect(
+        createBattle(startBattleId.add(new anchor.BN(101)))
+      ).rejects.toThrow(/ProgramPaused/);
+
+      await expect(
+        program.methods
+          .joinBattle(battleId, null)
+          .accounts({
+            playerTwo: playerTwo.publicKey,
+            playerOne: playerOne.publicKey,
+            adminAccount,
+            battleAccount,
+            playerTwoTokenAccount: playerTwoTokenAccount.address,
+            battleTokenAccount,
+            mint,
+            playerTwoProfile,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerTwo])
+          .rpc()
+      ).rejects.toThrow(/ProgramPaused/);
+
+      const tx = await program.methods
+        .withdrawFromBattle(battleId)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          battleAccount,
+          battleTokenAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          mint,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    });
+  });
+
+  describe('Rating bands', () => {
+    function createBattle(battleId: anchor.BN, ratingBand: object) {
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      return program.methods
+        .createBattle(battleId, { oracle: {} }, null, null, ratingBand, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: null,
+          battleAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          battleTokenAccount,
+          mint,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+    }
+
+    it('rejects a band with its minimum above its maximum', async () => {
+      await expect(
+        createBattle(startBattleId.add(new anchor.BN(120)), {
+          minRating: 1500,
+          maxRating: 1300,
+        })
+      ).rejects.toThrow(/InvalidRatingBand/);
+    });
+
+    it('rejects a challenger rated outside the band', async () => {
+      const battleId = startBattleId.add(new anchor.BN(121));
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      const { rating } = await program.account.playerProfile.fetch(
+        playerTwoProfile
+      );
+
+      await connection.confirmTransaction(
+        await createBattle(battleId, {
+          minRating: rating + 100,
+          maxRating: rating + 300,
+        }),
+        'confirmed'
+      );
+
+      const battleAccountData = await program.account.battle.fetch(
+        battleAccount
+      );
+      expect(battleAccountData.ratingBand?.minRating).toBe(rating + 100);
+
+      await expect(
+        program.methods
+          .joinBattle(battleId, null)
+          .accounts({
+            playerTwo: playerTwo.publicKey,
+            playerOne: playerOne.publicKey,
+            adminAccount,
+            battleAccount,
+            playerTwoTokenAccount: playerTwoTokenAccount.address,
+            battleTokenAccount,
+            mint,
+            playerTwoProfile,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerTwo])
+          .rpc()
+      ).rejects.toThrow(/RatingOutOfBand/);
+
+      const tx = await program.methods
+        .withdrawFromBattle(battleId)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          battleAccount,
+          battleTokenAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          mint,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    });
+  });
+
+  describe('Directed challenges', () => {
+    it('only lets the invited opponent join or decline', async () => {
+      const battleId = startBattleId.add(new anchor.BN(130));
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+      const invitedOpponent = Keypair.generate();
+      await airdrop(connection, invitedOpponent.publicKey);
+
+      const beforeTokenBalance = (
+        await getAccount(connection, playerOneTokenAccount.address)
+      ).amount;
+
+      let tx = await program.methods
+        .createBattle(
+          battleId,
+          { oracle: {} },
+          null,
+          null,
+          null,
+          invitedOpponent.publicKey
+        )
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: null,
+          battleAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          battleTokenAccount,
+          mint,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const battleAccountData = await program.account.battle.fetch(
+        battleAccount
+      );
+      expect(battleAccountData.invitedOpponent.toBase58()).toBe(
+        invitedOpponent.publicKey.toBase58()
+      );
+
+      await expect(
+        program.methods
+          .joinBattle(battleId, null)
+          .accounts({
+            playerTwo: playerTwo.publicKey,
+            playerOne: playerOne.publicKey,
+            adminAccount,
+            battleAccount,
+            playerTwoTokenAccount: playerTwoTokenAccount.address,
+            battleTokenAccount,
+            mint,
+            playerTwoProfile,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerTwo])
+          .rpc()
+      ).rejects.toThrow(/NotInvited/);
+
+      function declineBattle(decliner: Keypair) {
+        return program.methods
+          .declineBattle(battleId)
+          .accounts({
+            invitedOpponent: decliner.publicKey,
+            playerOne: playerOne.publicKey,
+            admin: admin.publicKey,
+            adminAccount,
+            battleAccount,
+            battleTokenAccount,
+            playerOneTokenAccount: playerOneTokenAccount.address,
+            mint,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([decliner])
+          .rpc();
+      }
+
+      await expect(declineBattle(playerTwo)).rejects.toThrow(/NotInvited/);
+
+      tx = await declineBattle(invitedOpponent);
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const [cancelled] = await getCpiEvents(tx);
+      expect(cancelled?.name).toBe('BattleCancelled');
+      expect(cancelled?.data.reason).toStrictEqual({ declined: {} });
+
+      const afterTokenBalance = (
+        await getAccount(connection, playerOneTokenAccount.address)
+      ).amount;
+      expect(afterTokenBalance.toString()).toBe(beforeTokenBalance.toString());
+      expect(await connection.getAccountInfo(battleAccount)).toBeNull();
+    });
+  });
+
+  describe('Config timelock', () => {
+    async function updateConfigDelay(configDelaySeconds: number) {
+      const tx = await program.methods
+        .updateConfigDelay(new anchor.BN(configDelaySeconds))
+        .accounts({
+          admin: admin.publicKey,
+          adminAccount,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+    }
+
+    function applyConfig() {
+      return program.methods.applyConfig().accounts({ adminAccount }).rpc();
+    }
+
+    afterAll(async () => {
+      // lowering the delay waits out the current one
+      await updateConfigDelay(0);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await connection.confirmTransaction(await applyConfig(), 'confirmed');
+    });
+
+    it('applies a price change only after the delay', async () => {
+      await updateConfigDelay(1);
+
+      const { battlePrice: currentBattlePrice } =
+        await program.account.admin.fetch(adminAccount);
+      const newBattlePrice = new anchor.BN(15_000);
+
+      const tx = await program.methods
+        .updateBattlePrice(newBattlePrice)
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          mint,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      let adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.battlePrice.toString()).toBe(
+        currentBattlePrice.toString()
+      );
+
+      await expect(applyConfig()).rejects.toThrow(/ConfigTimelocked/);
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      await connection.confirmTransaction(await applyConfig(), 'confirmed');
+
+      adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.battlePrice.toString()).toBe(
+        newBattlePrice.mul(new anchor.BN(LAMPORTS_PER_SOL)).toString()
+      );
+      expect(adminAccountData.pendingConfig.effectiveAt.toNumber()).toBe(0);
+
+      await expect(applyConfig()).rejects.toThrow(/NoPendingConfig/);
+    });
+
+    it('raises the delay right away and queues a lower one', async () => {
+      await updateConfigDelay(2);
+
+      let adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.configDelaySeconds.toNumber()).toBe(2);
+      expect(adminAccountData.pendingConfigDelaySeconds).toBeNull();
+
+      await updateConfigDelay(1);
+
+      adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.configDelaySeconds.toNumber()).toBe(2);
+      expect(adminAccountData.pendingConfigDelaySeconds?.toNumber()).toBe(1);
+
+      await expect(applyConfig()).rejects.toThrow(/ConfigTimelocked/);
+
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      await connection.confirmTransaction(await applyConfig(), 'confirmed');
+
+      adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.configDelaySeconds.toNumber()).toBe(1);
+      expect(adminAccountData.pendingConfigDelaySeconds).toBeNull();
+    });
+
+    it('rejects a config delay above the maximum', async () => {
+      await expect(
+        program.methods
+          .updateConfigDelay(new anchor.BN(31 * 24 * 60 * 60))
+          .accounts({
+            admin: admin.publicKey,
+            adminAccount,
+          })
+          .signers([admin.payer])
+          .rpc()
+      ).rejects.toThrow(/InvalidConfigDelay/);
+    });
+
+    it('applies mint config terms only after the delay', async () => {
+      const [solConfig] = PublicKey.findProgramAddressSync(
+        [MINT_CONFIG_SEED, PublicKey.default.toBuffer()],
+        program.programId
+      );
+      const { battlePrice: currentBattlePrice } =
+        await program.account.mintConfig.fetch(solConfig);
+
+      const tx = await program.methods
+        .addSolConfig(
+          new anchor.BN(2),
+          new anchor.BN(1),
+          new anchor.BN(10),
+          {
+            burnFeeBps: new anchor.BN(100),
+            treasuryFeeBps: new anchor.BN(0),
+            referrerFeeBps: new anchor.BN(1),
+          }
+        )
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          pendingMintConfig: getPendingMintConfig(PublicKey.default),
+          treasury: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      await expect(applyMintConfig(PublicKey.default)).rejects.toThrow(
+        /ConfigTimelocked/
+      );
+
+      let solConfigData = await program.account.mintConfig.fetch(solConfig);
+      expect(solConfigData.battlePrice.toString()).toBe(
+        currentBattlePrice.toString()
+      );
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      await connection.confirmTransaction(
+        await applyMintConfig(PublicKey.default),
+        'confirmed'
+      );
+
+      solConfigData = await program.account.mintConfig.fetch(solConfig);
+      expect(solConfigData.battlePrice.toString()).toBe(
+        (2 * LAMPORTS_PER_SOL).toString()
+      );
+    });
+
+    it('keeps a same-mint update from skipping the delay', async () => {
+      const { battlePrice: currentBattlePrice } =
+        await program.account.admin.fetch(adminAccount);
+      const newBattlePrice = new anchor.BN(12_000);
+
+      const tx = await program.methods
+        .updateBattlePrice(newBattlePrice)
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          mint,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      await expect(
+        program.methods
+          .updateMint(
+            new anchor.BN(20_000),
+            new anchor.BN(0),
+            new anchor.BN(50_000)
+          )
+          .accounts({
+            configManager: admin.publicKey,
+            adminAccount,
+            treasuryTokenAccount: adminTokenAccount.address,
+            mint,
+          })
+          .signers([admin.payer])
+          .rpc()
+      ).rejects.toThrow(/MintUnchanged/);
+
+      const adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.battlePrice.toString()).toBe(
+        currentBattlePrice.toString()
+      );
+      expect(adminAccountData.pendingConfig.battlePrice?.toString()).toBe(
+        newBattlePrice.mul(new anchor.BN(LAMPORTS_PER_SOL)).toString()
+      );
+    });
+  });
+
+  describe('Concurrent battles', () => {
+    it('lets a player host several battles at once', async () => {
+      const battleIds = [
+        startBattleId.add(new anchor.BN(1)),
+        startBattleId.add(new anchor.BN(2)),
+      ];
+
+      for (const battleId of battleIds) {
+        const [battleAccount, battleTokenAccount] = getBattleAddresses(
+          playerOne.publicKey,
+          battleId
+        );
+
+        const tx = await program.methods
+          .createBattle(battleId, { oracle: {} }, null, null, null, null)
+          .accounts({
+            playerOne: playerOne.publicKey,
+            adminAccount,
+            mintConfig: null,
+            battleAccount,
+            playerOneTokenAccount: playerOneTokenAccount.address,
+            battleTokenAccount,
+            mint,
+            playerOneProfile,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerOne])
+          .rpc();
+
+        await connection.confirmTransaction(tx, 'confirmed');
+      }
+
+      for (const battleId of battleIds) {
+        const [battleAccount, battleTokenAccount] = getBattleAddresses(
+          playerOne.publicKey,
+          battleId
+        );
+
+        const battleAccountData = await program.account.battle.fetch(
+          battleAccount
+        );
+        expect(battleAccountData.battleId.toString()).toBe(battleId.toString());
+        expect(battleAccountData.battleStatus).toStrictEqual({ pending: {} });
+
+        const tx = await program.methods
+          .withdrawFromBattle(battleId)
+          .accounts({
+            playerOne: playerOne.publicKey,
+            admin: admin.publicKey,
+            adminAccount,
+            battleAccount,
+            battleTokenAccount,
+            playerOneTokenAccount: playerOneTokenAccount.address,
+            mint,
+            systemProgram: SystemProgram.programId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([playerOne])
+          .rpc();
+
+        await connection.confirmTransaction(tx, 'confirmed');
+      }
+    });
+  });
+
+  describe('Admin', () => {
+    it('withdraws', async () => {
+      startBattleId.add(new anchor.BN(new Date().getTime()));
+
+      let tx = await program.methods
+        .createBattle(startBattleId, { oracle: {} }, null, null, null, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: null,
+          battleAccount: battleAccountAddress,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          battleTokenAccount: battleTokenAccountAddress,
+          mint,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      tx = await program.methods
+        .joinBattle(startBattleId, null)
+        .accounts({
+          playerTwo: playerTwo.publicKey,
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          battleAccount: battleAccountAddress,
+          playerTwoTokenAccount: playerTwoTokenAccount.address,
+          battleTokenAccount: battleTokenAccountAddress,
+          mint,
+          playerTwoProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerTwo])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      tx = await program.methods
+        .adminWithdrawal(startBattleId)
+        .accounts({
+          treasuryAuthority: admin.publicKey,
+          playerOne: playerOne.publicKey,
+          playerTwo: playerTwo.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          battleAccount: battleAccountAddress,
+          battleTokenAccount: battleTokenAccountAddress,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          playerTwoTokenAccount: playerTwoTokenAccount.address,
+          treasuryTokenAccount: adminTokenAccount.address,
+          mint,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      try {
+        await program.account.battle.fetch(battleAccountAddress);
+      } catch (error) {
+        expect(error).toBeInstanceOf(Error);
+      }
+    });
+
+    it('refunds exact deposits and sweeps dust to the treasury', async () => {
+      const battleId = startBattleId.add(new anchor.BN(110));
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      let tx = await program.methods
+        .createBattle(battleId, { oracle: {} }, null, null, null, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: null,
+          battleAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          battleTokenAccount,
+          mint,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      // a stray base unit sent straight to the battle token account
+      await transfer(
+        connection,
+        admin.payer,
+        playerOneTokenAccount.address,
+        battleTokenAccount,
+        playerOne,
+        1
+      );
+
+      const { playerOneDeposit } = await program.account.battle.fetch(
+        battleAccount
+      );
+      const beforePlayerOneTokenAccount = await getAccount(
+        connection,
+        playerOneTokenAccount.address
+      );
+      const beforeTreasuryTokenAccount = await getAccount(
+        connection,
+        adminTokenAccount.address
+      );
+
+      // the battle is still pending so there is no player two to refund
+      tx = await program.methods
+        .adminWithdrawal(battleId)
+        .accounts({
+          treasuryAuthority: admin.publicKey,
+          playerOne: playerOne.publicKey,
+          playerTwo: PublicKey.default,
+          admin: admin.publicKey,
+          adminAccount,
+          battleAccount,
+          battleTokenAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          playerTwoTokenAccount: null,
+          treasuryTokenAccount: adminTokenAccount.address,
+          mint,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const afterPlayerOneTokenAccount = await getAccount(
+        connection,
+        playerOneTokenAccount.address
+      );
+      const afterTreasuryTokenAccount = await getAccount(
+        connection,
+        adminTokenAccount.address
+      );
+
+      expect(
+        (
+          afterPlayerOneTokenAccount.amount - beforePlayerOneTokenAccount.amount
+        ).toString()
+      ).toBe(playerOneDeposit.toString());
+      expect(
+        afterTreasuryTokenAccount.amount - beforeTreasuryTokenAccount.amount
+      ).toBe(BigInt(1));
+      expect(await connection.getAccountInfo(battleTokenAccount)).toBeNull();
+    });
+
+    it('updates battle price', async () => {
+      const newBattlePrice = new anchor.BN(20_000);
+
+      const tx = await program.methods
+        .updateBattlePrice(newBattlePrice)
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          mint,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const adminAccountData = await program.account.admin.fetch(adminAccount);
+
+      expect(adminAccountData.battlePrice.toString()).toBe(
+        newBattlePrice.mul(new anchor.BN(LAMPORTS_PER_SOL)).toString()
+      );
+    });
+
+    it('rejects a zero battle price', async () => {
+      await expect(
+        program.methods
+          .updateBattlePrice(new anchor.BN(0))
+          .accounts({
+            configManager: admin.publicKey,
+            adminAccount,
+            mint,
+          })
+          .signers([admin.payer])
+          .rpc()
+      ).rejects.toThrow();
+    });
+
+    it('enforces battle price bounds', async () => {
+      const tx = await program.methods
+        .updateStakeBounds(new anchor.BN(0), new anchor.BN(50_000))
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          mint,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.minStake.toNumber()).toBe(0);
+      expect(adminAccountData.maxStake.toString()).toBe(
+        new anchor.BN(50_000).mul(new anchor.BN(LAMPORTS_PER_SOL)).toString()
+      );
+
+      await expect(
+        program.methods
+          .updateBattlePrice(new anchor.BN(60_000))
+          .accounts({
+            configManager: admin.publicKey,
+            adminAccount,
+            mint,
+          })
+          .signers([admin.payer])
+          .rpc()
+      ).rejects.toThrow();
+    });
+
+    it('rejects a burn fee above 10,000 bps', async () => {
+      await expect(
+        program.methods
+          .updateBurnFeeBps(new anchor.BN(10_001))
+          .accounts({
+            configManager: admin.publicKey,
+            adminAccount,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin.payer])
+          .rpc()
+      ).rejects.toThrow();
+    });
+
+    it('updates burn fee bps', async () => {
+      const newBurnFeeBps = new anchor.BN(200);
+
+      const tx = await program.methods
+        .updateBurnFeeBps(newBurnFeeBps)
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const adminAccountData = await program.account.admin.fetch(adminAccount);
+
+      expect(adminAccountData.burnFeeBps.toString()).toBe(
+        newBurnFeeBps.toString()
+      );
+    });
+
+    it('migrates the admin account to the current version', async () => {
+      const tx = await program.methods
+        .migrateAdmin()
+        .accounts({
+          admin: admin.publicKey,
+          adminAccount,
+          treasuryTokenAccount: adminTokenAccount.address,
+          resultAuthority: resultAuthority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.version).toBe(1);
+      expect(adminAccountData.configManager.toBase58()).toBe(
+        admin.publicKey.toBase58()
+      );
+    });
+
+    it('delegates the config manager role', async () => {
+      const configManager = Keypair.generate();
+
+      async function updateRoles(newConfigManager: PublicKey) {
+        const tx = await program.methods
+          .updateRoles()
+          .accounts({
+            admin: admin.publicKey,
+            adminAccount,
+            configManager: newConfigManager,
+            treasuryAuthority: admin.publicKey,
+            pauser: admin.publicKey,
+          })
+          .signers([admin.payer])
+          .rpc();
+
+        await connection.confirmTransaction(tx, 'confirmed');
+      }
+
+      function updateBattleTimeout(signer: Keypair) {
+        return program.methods
+          .updateBattleTimeout(new anchor.BN(0))
+          .accounts({
+            configManager: signer.publicKey,
+            adminAccount,
+          })
+          .signers([signer])
+          .rpc();
+      }
+
+      await updateRoles(configManager.publicKey);
+
+      // the admin keeps control of the roles but no longer of the config
+      await expect(updateBattleTimeout(admin.payer)).rejects.toThrow();
+      await updateBattleTimeout(configManager);
+
+      await updateRoles(admin.publicKey);
+    });
+
+    it('hands the admin over in two steps', async () => {
+      const newAdmin = Keypair.generate();
+
+      async function proposeAdmin(from: Keypair, to: PublicKey) {
+        const tx = await program.methods
+          .proposeAdmin()
+          .accounts({
+            admin: from.publicKey,
+            adminAccount,
+            pendingAdmin: to,
+          })
+          .signers([from])
+          .rpc();
+
+        await connection.confirmTransaction(tx, 'confirmed');
+      }
+
+      async function acceptAdmin(pendingAdmin: Keypair) {
+        const tx = await program.methods
+          .acceptAdmin()
+          .accounts({
+            pendingAdmin: pendingAdmin.publicKey,
+            adminAccount,
+          })
+          .signers([pendingAdmin])
+          .rpc();
+
+        await connection.confirmTransaction(tx, 'confirmed');
+      }
+
+      await proposeAdmin(admin.payer, newAdmin.publicKey);
+
+      const tx = await program.methods
+        .cancelAdminTransfer()
+        .accounts({
+          admin: admin.publicKey,
+          adminAccount,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      await expect(acceptAdmin(newAdmin)).rejects.toThrow();
+
+      await proposeAdmin(admin.payer, newAdmin.publicKey);
+      await acceptAdmin(newAdmin);
+
+      let adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.admin.toBase58()).toBe(
+        newAdmin.publicKey.toBase58()
+      );
+      expect(adminAccountData.pendingAdmin.toBase58()).toBe(
+        PublicKey.default.toBase58()
+      );
+
+      // hand it back for the remaining tests
+      await proposeAdmin(newAdmin, admin.publicKey);
+      await acceptAdmin(admin.payer);
+
+      adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.admin.toBase58()).toBe(admin.publicKey.toBase58());
+    });
+
+    it('moves the roles the outgoing admin held to the new admin', async () => {
+      const newAdmin = Keypair.generate();
+      const delegatedPauser = Keypair.generate().publicKey;
+
+      async function updateRoles(
+        signer: Keypair,
+        configManager: PublicKey,
+        treasuryAuthority: PublicKey,
+        pauser: PublicKey
+      ) {
+        const tx = await program.methods
+          .updateRoles()
+          .accounts({
+            admin: signer.publicKey,
+            adminAccount,
+            configManager,
+            treasuryAuthority,
+            pauser,
+          })
+          .signers([signer])
+          .rpc();
+
+        await connection.confirmTransaction(tx, 'confirmed');
+      }
+
+      async function handOver(from: Keypair, to: Keypair) {
+        let tx = await program.methods
+          .proposeAdmin()
+          .accounts({
+            admin: from.publicKey,
+            adminAccount,
+            pendingAdmin: to.publicKey,
+          })
+          .signers([from])
+          .rpc();
+
+        await connection.confirmTransaction(tx, 'confirmed');
+
+        tx = await program.methods
+          .acceptAdmin()
+          .accounts({
+            pendingAdmin: to.publicKey,
+            adminAccount,
+          })
+          .signers([to])
+          .rpc();
+
+        await connection.confirmTransaction(tx, 'confirmed');
+      }
+
+      // the pauser is delegated, the other roles are still the admin's
+      await updateRoles(
+        admin.payer,
+        admin.publicKey,
+        admin.publicKey,
+        delegatedPauser
+      );
+      await handOver(admin.payer, newAdmin);
+
+      const adminAccountData = await program.account.admin.fetch(adminAccount);
+      expect(adminAccountData.configManager.toBase58()).toBe(
+        newAdmin.publicKey.toBase58()
+      );
+      expect(adminAccountData.treasuryAuthority.toBase58()).toBe(
+        newAdmin.publicKey.toBase58()
+      );
+      expect(adminAccountData.pauser.toBase58()).toBe(
+        delegatedPauser.toBase58()
+      );
+
+      // hand everything back for the remaining tests
+      await handOver(newAdmin, admin.payer);
+      await updateRoles(
+        admin.payer,
+        admin.publicKey,
+        admin.publicKey,
+        admin.publicKey
+      );
+    });
+
+    it('updates the mint without stranding open battles', async () => {
+      const battleId = startBattleId.add(new anchor.BN(90));
+      const [battleAccount, battleTokenAccount] = getBattleAddresses(
+        playerOne.publicKey,
+        battleId
+      );
+
+      let tx = await program.methods
+        .createBattle(battleId, { oracle: {} }, null, null, null, null)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          adminAccount,
+          mintConfig: null,
+          battleAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          battleTokenAccount,
+          mint,
+          playerOneProfile,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      const newMint = await createMint(
+        connection,
+        admin.payer,
+        admin.publicKey,
+        admin.publicKey,
+        6
+      );
+
+      const newTreasuryTokenAccount = await getOrCreateAssociatedTokenAccount(
+        connection,
+        admin.payer,
+        newMint,
+        admin.publicKey
+      );
+
+      tx = await program.methods
+        .updateMint(
+          new anchor.BN(10),
+          new anchor.BN(1),
+          new anchor.BN(1_000)
+        )
+        .accounts({
+          configManager: admin.publicKey,
+          adminAccount,
+          treasuryTokenAccount: newTreasuryTokenAccount.address,
+          mint: newMint,
+        })
+        .signers([admin.payer])
+        .rpc();
+
+      await connection.confirmTransaction(tx, 'confirmed');
+
+      // the open battle still withdraws in the mint it was created with
+      tx = await program.methods
+        .withdrawFromBattle(battleId)
+        .accounts({
+          playerOne: playerOne.publicKey,
+          admin: admin.publicKey,
+          adminAccount,
+          battleAccount,
+          battleTokenAccount,
+          playerOneTokenAccount: playerOneTokenAccount.address,
+          mint,
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerOne])
         .rpc();
 
       await connection.confirmTransaction(tx, 'confirmed');
 
       const adminAccountData = await program.account.admin.fetch(adminAccount);
 
+      expect(adminAccountData.treasuryTokenAccount.toBase58()).toBe(
+        newTreasuryTokenAccount.address.toBase58()
+      );
       expect(adminAccountData.mint.toBase58()).toBe(newMint.toBase58());
+      // the price and bounds are in the new mint's base units
+      expect(adminAccountData.battlePrice.toNumber()).toBe(10_000_000);
+      expect(adminAccountData.minStake.toNumber()).toBe(1_000_000);
+      expect(adminAccountData.maxStake.toNumber()).toBe(1_000_000_000);
+      expect(adminAccountData.pendingConfig.battlePrice).toBeNull();
     });
   });
 });